@@ -0,0 +1,312 @@
+use syn::{
+    meta::ParseNestedMeta, Attribute, Data, DataStruct, DeriveInput, ExprPath, Fields, Ident,
+    LitStr,
+};
+
+use crate::{case::RenameRule, error::Errors};
+
+/// The name of the helper attribute that configures all derive macros of this crate
+const ATTR_NAME: &str = "pyo3_more";
+
+/// Attributes of a container, i.e. `#[pyo3_more(...)]` placed on a struct or an enum
+#[derive(Default)]
+pub struct ContainerAttrs {
+    /// `rename_all = "rule"`: The case conversion applied to the names of all fields (struct) or variants (enum)
+    pub rename_all: Option<RenameRule>,
+    /// `tuple`: The named fields are converted into a tuple in their declaration order
+    pub tuple: bool,
+    /// `tag = "key"`: The key that holds the name of the variant (enum)
+    pub tag: Option<LitStr>,
+    /// `content = "key"`: The key that holds the fields of the variant (enum), which are otherwise
+    /// stored next to the tag
+    pub content: Option<LitStr>,
+    /// `strip_trailing_underscore`: The trailing underscore is stripped from the names of fields
+    /// that are Python keywords without it, e.g. `lambda_`
+    pub strip_trailing_underscore: bool,
+    /// `extra(key = "key", with = "path")`: Additional items that are not stored in fields
+    pub extras: Vec<ExtraAttrs>,
+    /// `class = "module.Class"`: The path of the Python class that is constructed from the fields
+    pub class: Option<LitStr>,
+    /// `diff`: The fields are also applied only if they differ from their previous values
+    pub diff: bool,
+    /// The keys of all attributes that were parsed successfully, in their order of appearance
+    pub keys: Vec<Ident>,
+}
+
+/// Attributes of an additional item computed by a method, i.e. `extra(key = "key", with = "path")`
+pub struct ExtraAttrs {
+    /// `key = "key"`: The key of the item
+    pub key: LitStr,
+    /// `with = "path"`: The value of the item is computed by `path(&self)`
+    pub with: ExprPath,
+}
+
+impl ContainerAttrs {
+    /// Parse the attributes of the struct or enum `input`, reporting the keys that do not apply to
+    /// its shape. The keys are shared by all derives of this crate, which ignore the keys of the
+    /// other derives, so that a type can derive several of them with the same attributes
+    pub fn from_input(input: &DeriveInput, errors: &mut Errors) -> Self {
+        let container_attrs = Self::from_attrs(&input.attrs, errors);
+        let (supported, shape): (&[&str], _) = match &input.data {
+            Data::Struct(DataStruct {
+                fields: Fields::Named(_),
+                ..
+            }) => (
+                &[
+                    "rename_all",
+                    "tuple",
+                    "strip_trailing_underscore",
+                    "extra",
+                    "class",
+                    "diff",
+                ],
+                "structs with named fields",
+            ),
+            Data::Struct(_) => (&[], "structs without named fields"),
+            Data::Enum(_) => (
+                &[
+                    "rename_all",
+                    "tag",
+                    "content",
+                    "strip_trailing_underscore",
+                    "extra",
+                    "class",
+                ],
+                "enums",
+            ),
+            Data::Union(_) => (&[], "unions"),
+        };
+        for key in &container_attrs.keys {
+            if !supported.iter().any(|supported| key == supported) {
+                errors.push(syn::Error::new(
+                    key.span(),
+                    format!("`{key}` attribute is not supported for {shape}"),
+                ));
+            }
+        }
+        container_attrs
+    }
+
+    fn from_attrs(attrs: &[Attribute], errors: &mut Errors) -> Self {
+        let mut container_attrs = Self::default();
+        parse_attrs(attrs, errors, |meta| {
+            let result = if meta.path.is_ident("rename_all") {
+                let rule: LitStr = meta.value()?.parse()?;
+                set_once(
+                    meta,
+                    &mut container_attrs.rename_all,
+                    RenameRule::from_lit(&rule)?,
+                )
+            } else if meta.path.is_ident("tuple") {
+                set_flag(meta, &mut container_attrs.tuple)
+            } else if meta.path.is_ident("tag") {
+                set_once(meta, &mut container_attrs.tag, meta.value()?.parse()?)
+            } else if meta.path.is_ident("content") {
+                set_once(meta, &mut container_attrs.content, meta.value()?.parse()?)
+            } else if meta.path.is_ident("strip_trailing_underscore") {
+                set_flag(meta, &mut container_attrs.strip_trailing_underscore)
+            } else if meta.path.is_ident("class") {
+                set_once(meta, &mut container_attrs.class, meta.value()?.parse()?)
+            } else if meta.path.is_ident("diff") {
+                set_flag(meta, &mut container_attrs.diff)
+            } else if meta.path.is_ident("extra") {
+                let (mut key, mut with) = (None, None);
+                parse_nested(meta, |meta| {
+                    if meta.path.is_ident("key") {
+                        set_once(meta, &mut key, meta.value()?.parse()?)
+                    } else if meta.path.is_ident("with") {
+                        let path: LitStr = meta.value()?.parse()?;
+                        set_once(meta, &mut with, path.parse()?)
+                    } else {
+                        Err(unknown_key(meta, "extra"))
+                    }
+                })?;
+                match (key, with) {
+                    (Some(key), Some(with)) => {
+                        container_attrs.extras.push(ExtraAttrs { key, with });
+                        Ok(())
+                    }
+                    _ => Err(meta.error(
+                        "`extra` attribute requires both `key = \"key\"` and `with = \"path\"`",
+                    )),
+                }
+            } else {
+                Err(unknown_key(meta, "container"))
+            };
+            if let (Ok(()), Some(key)) = (&result, meta.path.get_ident()) {
+                container_attrs.keys.push(key.clone());
+            }
+            result
+        });
+        container_attrs
+    }
+}
+
+/// Attributes of an enum variant, i.e. `#[pyo3_more(...)]` placed on a variant
+#[derive(Default)]
+pub struct VariantAttrs {
+    /// `rename = "name"`: The name used for the variant instead of its identifier
+    pub rename: Option<LitStr>,
+    /// `rename_all = "rule"`: The case conversion applied to the names of all fields of the variant
+    pub rename_all: Option<RenameRule>,
+    /// `alias = "name"`: An additional name that is accepted for the variant on extraction (repeatable)
+    pub aliases: Vec<LitStr>,
+}
+
+impl VariantAttrs {
+    pub fn from_attrs(attrs: &[Attribute], errors: &mut Errors) -> Self {
+        let mut variant_attrs = Self::default();
+        parse_attrs(attrs, errors, |meta| {
+            if meta.path.is_ident("rename") {
+                set_once(meta, &mut variant_attrs.rename, meta.value()?.parse()?)
+            } else if meta.path.is_ident("rename_all") {
+                let rule: LitStr = meta.value()?.parse()?;
+                set_once(
+                    meta,
+                    &mut variant_attrs.rename_all,
+                    RenameRule::from_lit(&rule)?,
+                )
+            } else if meta.path.is_ident("alias") {
+                variant_attrs.aliases.push(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(unknown_key(meta, "variant"))
+            }
+        });
+        variant_attrs
+    }
+}
+
+/// Attributes of a field, i.e. `#[pyo3_more(...)]` placed on a field
+#[derive(Default)]
+pub struct FieldAttrs {
+    /// `rename = "key"`: The key used for the field instead of its name
+    pub rename: Option<LitStr>,
+    /// `skip`: The field is omitted from the conversion
+    pub skip: bool,
+    /// `skip_if = "path"`: The field is omitted if `path(&field)` returns `true`
+    pub skip_if: Option<ExprPath>,
+    /// `flatten`: The items of the field are merged into the dictionary instead of being nested
+    pub flatten: bool,
+    /// `nested`: The field is converted into a nested dictionary by `pyo3_macros_more::TryIntoPyDict`
+    pub nested: bool,
+    /// `with = "module"`: The field is converted by `module::to_py(&field, py)` and extracted by
+    /// `module::from_py(value)` instead of its own conversions
+    pub with: Option<ExprPath>,
+    /// `path = "attr.attr"`: The path of the attribute that holds the field, instead of its name
+    pub path: Option<LitStr>,
+    /// `default`: The field is initialized with `Default::default()` if its attribute is missing
+    pub default: bool,
+}
+
+impl FieldAttrs {
+    pub fn from_attrs(attrs: &[Attribute], errors: &mut Errors) -> Self {
+        let mut field_attrs = Self::default();
+        parse_attrs(attrs, errors, |meta| {
+            if meta.path.is_ident("rename") {
+                set_once(meta, &mut field_attrs.rename, meta.value()?.parse()?)
+            } else if meta.path.is_ident("skip") {
+                set_flag(meta, &mut field_attrs.skip)
+            } else if meta.path.is_ident("skip_if") {
+                let path: LitStr = meta.value()?.parse()?;
+                set_once(meta, &mut field_attrs.skip_if, path.parse()?)
+            } else if meta.path.is_ident("flatten") {
+                set_flag(meta, &mut field_attrs.flatten)
+            } else if meta.path.is_ident("nested") {
+                set_flag(meta, &mut field_attrs.nested)
+            } else if meta.path.is_ident("with") {
+                let path: LitStr = meta.value()?.parse()?;
+                set_once(meta, &mut field_attrs.with, path.parse()?)
+            } else if meta.path.is_ident("path") {
+                set_once(meta, &mut field_attrs.path, meta.value()?.parse()?)
+            } else if meta.path.is_ident("default") {
+                set_flag(meta, &mut field_attrs.default)
+            } else {
+                Err(unknown_key(meta, "field"))
+            }
+        });
+        field_attrs
+    }
+}
+
+/// Parse all `#[pyo3_more(...)]` attributes key by key, storing the errors of invalid keys
+/// while continuing with the remaining keys
+fn parse_attrs(
+    attrs: &[Attribute],
+    errors: &mut Errors,
+    mut parse_key: impl FnMut(&ParseNestedMeta) -> syn::Result<()>,
+) {
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident(ATTR_NAME)) {
+        let result = attr.parse_nested_meta(|meta| {
+            if let Err(err) = parse_key(&meta) {
+                errors.push(err);
+                skip_value(&meta)?;
+            }
+            Ok(())
+        });
+        errors.take(result);
+    }
+}
+
+/// Parse the nested keys of an attribute key such as `extra(...)` key by key, returning the
+/// errors of all invalid keys together
+fn parse_nested(
+    meta: &ParseNestedMeta,
+    mut parse_key: impl FnMut(&ParseNestedMeta) -> syn::Result<()>,
+) -> syn::Result<()> {
+    let mut errors = Errors::default();
+    meta.parse_nested_meta(|meta| {
+        if let Err(err) = parse_key(&meta) {
+            errors.push(err);
+            skip_value(&meta)?;
+        }
+        Ok(())
+    })?;
+    errors.finish(())
+}
+
+/// Skip the remaining tokens of an attribute key up to the next `,`
+fn skip_value(meta: &ParseNestedMeta) -> syn::Result<()> {
+    while !meta.input.is_empty() && !meta.input.peek(syn::Token![,]) {
+        meta.input.parse::<proc_macro2::TokenTree>()?;
+    }
+    Ok(())
+}
+
+/// Store the value of an attribute key that can be specified only once
+fn set_once<T>(meta: &ParseNestedMeta, slot: &mut Option<T>, value: T) -> syn::Result<()> {
+    if slot.is_some() {
+        return Err(meta.error(format!(
+            "duplicate `{}` attribute",
+            path_to_string(&meta.path)
+        )));
+    }
+    *slot = Some(value);
+    Ok(())
+}
+
+/// Store an attribute key that acts as a flag without any value
+fn set_flag(meta: &ParseNestedMeta, flag: &mut bool) -> syn::Result<()> {
+    if *flag {
+        return Err(meta.error(format!(
+            "duplicate `{}` attribute",
+            path_to_string(&meta.path)
+        )));
+    }
+    *flag = true;
+    Ok(())
+}
+
+/// Error for an attribute key that is not supported in the given position
+fn unknown_key(meta: &ParseNestedMeta, position: &str) -> syn::Error {
+    meta.error(format!(
+        "unknown {position} attribute `{}`",
+        path_to_string(&meta.path)
+    ))
+}
+
+fn path_to_string(path: &syn::Path) -> String {
+    quote::ToTokens::to_token_stream(path)
+        .to_string()
+        .replace(' ', "")
+}
@@ -0,0 +1,57 @@
+use std::collections::HashSet;
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{Generics, Ident, Lifetime, LifetimeParam, Type, TypeParamBound, WherePredicate};
+
+/// Add `bound` to each type parameter of `generics` that appears in any of `field_types`
+pub fn with_bound<'a>(
+    generics: &Generics,
+    field_types: impl IntoIterator<Item = &'a Type>,
+    bound: &TypeParamBound,
+) -> Generics {
+    // The identifiers that appear anywhere within the types of the fields
+    let mut idents = HashSet::new();
+    for field_ty in field_types {
+        collect_idents(field_ty.to_token_stream(), &mut idents);
+    }
+
+    let mut generics = generics.clone();
+    let predicates: Vec<WherePredicate> = generics
+        .type_params()
+        .filter(|type_param| idents.contains(&type_param.ident))
+        .map(|type_param| {
+            let ident = &type_param.ident;
+            syn::parse_quote!(#ident: #bound)
+        })
+        .collect();
+    generics.make_where_clause().predicates.extend(predicates);
+    generics
+}
+
+/// Insert the `'__py` lifetime of the extracted Python object as the first parameter of `generics`,
+/// bounded by the lifetime parameters of `input_generics` so that borrowed fields can outlive it
+pub fn with_py_lifetime(generics: &Generics, input_generics: &Generics) -> Generics {
+    let mut py_lifetime =
+        LifetimeParam::new(Lifetime::new("'__py", proc_macro2::Span::call_site()));
+    py_lifetime.bounds.extend(
+        input_generics
+            .lifetimes()
+            .map(|lifetime_def| lifetime_def.lifetime.clone()),
+    );
+    let mut generics = generics.clone();
+    generics.params.insert(0, py_lifetime.into());
+    generics
+}
+
+fn collect_idents(tokens: TokenStream, idents: &mut HashSet<Ident>) {
+    for token in tokens {
+        match token {
+            TokenTree::Ident(ident) => {
+                idents.insert(ident);
+            }
+            TokenTree::Group(group) => collect_idents(group.stream(), idents),
+            TokenTree::Punct(_) | TokenTree::Literal(_) => {}
+        }
+    }
+}
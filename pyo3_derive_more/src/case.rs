@@ -0,0 +1,210 @@
+/// Case conversion rule applied to keys, i.e. `#[pyo3_more(rename_all = "...")]`
+#[derive(Clone, Copy)]
+pub enum RenameRule {
+    /// `lowercase`
+    Lower,
+    /// `UPPERCASE`
+    Upper,
+    /// `PascalCase`
+    Pascal,
+    /// `camelCase`
+    Camel,
+    /// `snake_case`
+    Snake,
+    /// `SCREAMING_SNAKE_CASE`
+    ScreamingSnake,
+    /// `kebab-case`
+    Kebab,
+    /// `SCREAMING-KEBAB-CASE`
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    /// All supported rules together with their names used in the attribute
+    const ALL: [(&'static str, Self); 8] = [
+        ("lowercase", Self::Lower),
+        ("UPPERCASE", Self::Upper),
+        ("PascalCase", Self::Pascal),
+        ("camelCase", Self::Camel),
+        ("snake_case", Self::Snake),
+        ("SCREAMING_SNAKE_CASE", Self::ScreamingSnake),
+        ("kebab-case", Self::Kebab),
+        ("SCREAMING-KEBAB-CASE", Self::ScreamingKebab),
+    ];
+
+    pub fn from_lit(lit: &syn::LitStr) -> syn::Result<Self> {
+        let name = lit.value();
+        Self::ALL
+            .iter()
+            .find(|(rule_name, _)| *rule_name == name)
+            .map(|(_, rule)| *rule)
+            .ok_or_else(|| {
+                let names: Vec<_> = Self::ALL
+                    .iter()
+                    .map(|(rule_name, _)| format!("\"{rule_name}\""))
+                    .collect();
+                syn::Error::new(
+                    lit.span(),
+                    format!(
+                        "unknown rename rule `{name}`, expected one of {}",
+                        names.join(", ")
+                    ),
+                )
+            })
+    }
+
+    /// Apply the rule to a `snake_case` field name
+    pub fn apply_to_field(self, field: &str) -> String {
+        match self {
+            Self::Lower | Self::Snake => field.to_owned(),
+            Self::Upper | Self::ScreamingSnake => field.to_ascii_uppercase(),
+            Self::Pascal => {
+                let mut pascal = String::with_capacity(field.len());
+                let mut capitalize = true;
+                for ch in field.chars() {
+                    if ch == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(ch.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(ch);
+                    }
+                }
+                pascal
+            }
+            Self::Camel => {
+                let pascal = Self::Pascal.apply_to_field(field);
+                let mut chars = pascal.chars();
+                chars.next().map_or_else(String::new, |first| {
+                    first.to_ascii_lowercase().to_string() + chars.as_str()
+                })
+            }
+            Self::Kebab => field.replace('_', "-"),
+            Self::ScreamingKebab => Self::ScreamingSnake.apply_to_field(field).replace('_', "-"),
+        }
+    }
+
+    /// Apply the rule to a `PascalCase` variant name
+    pub fn apply_to_variant(self, variant: &str) -> String {
+        match self {
+            Self::Pascal => variant.to_owned(),
+            Self::Lower => variant.to_ascii_lowercase(),
+            Self::Upper => variant.to_ascii_uppercase(),
+            Self::Camel => {
+                let mut chars = variant.chars();
+                chars.next().map_or_else(String::new, |first| {
+                    first.to_ascii_lowercase().to_string() + chars.as_str()
+                })
+            }
+            Self::Snake => {
+                let mut snake = String::with_capacity(variant.len() + 4);
+                for (i, ch) in variant.char_indices() {
+                    if i > 0 && ch.is_uppercase() {
+                        snake.push('_');
+                    }
+                    snake.push(ch.to_ascii_lowercase());
+                }
+                snake
+            }
+            Self::ScreamingSnake => Self::Snake.apply_to_variant(variant).to_ascii_uppercase(),
+            Self::Kebab => Self::Snake.apply_to_variant(variant).replace('_', "-"),
+            Self::ScreamingKebab => Self::ScreamingSnake
+                .apply_to_variant(variant)
+                .replace('_', "-"),
+        }
+    }
+}
+
+/// Keywords of Python that cannot be used as names of keyword arguments
+const PYTHON_KEYWORDS: [&str; 35] = [
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+/// Strip the trailing underscore from a name that would otherwise be a Python keyword,
+/// e.g. `lambda_` becomes `lambda`
+pub fn strip_keyword_underscore(name: &str) -> &str {
+    match name.strip_suffix('_') {
+        Some(stem) if PYTHON_KEYWORDS.contains(&stem) => stem,
+        _ => name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_to_field() {
+        let expected = [
+            ("lowercase", "learning_rate"),
+            ("UPPERCASE", "LEARNING_RATE"),
+            ("PascalCase", "LearningRate"),
+            ("camelCase", "learningRate"),
+            ("snake_case", "learning_rate"),
+            ("SCREAMING_SNAKE_CASE", "LEARNING_RATE"),
+            ("kebab-case", "learning-rate"),
+            ("SCREAMING-KEBAB-CASE", "LEARNING-RATE"),
+        ];
+        for (rule, key) in expected {
+            assert_eq!(
+                rule_named(rule).apply_to_field("learning_rate"),
+                key,
+                "{rule}"
+            );
+        }
+        assert_eq!(RenameRule::Camel.apply_to_field("lr"), "lr");
+        assert_eq!(RenameRule::Pascal.apply_to_field("beta_1"), "Beta1");
+        assert_eq!(RenameRule::Camel.apply_to_field(""), "");
+    }
+
+    #[test]
+    fn apply_to_variant() {
+        let expected = [
+            ("lowercase", "stepdecay"),
+            ("UPPERCASE", "STEPDECAY"),
+            ("PascalCase", "StepDecay"),
+            ("camelCase", "stepDecay"),
+            ("snake_case", "step_decay"),
+            ("SCREAMING_SNAKE_CASE", "STEP_DECAY"),
+            ("kebab-case", "step-decay"),
+            ("SCREAMING-KEBAB-CASE", "STEP-DECAY"),
+        ];
+        for (rule, name) in expected {
+            assert_eq!(
+                rule_named(rule).apply_to_variant("StepDecay"),
+                name,
+                "{rule}"
+            );
+        }
+        assert_eq!(RenameRule::Snake.apply_to_variant("Adam"), "adam");
+        assert_eq!(RenameRule::Camel.apply_to_variant(""), "");
+    }
+
+    #[test]
+    fn from_lit() {
+        let lit = syn::LitStr::new("Title Case", proc_macro2::Span::call_site());
+        let err = RenameRule::from_lit(&lit).err().unwrap();
+        assert!(err
+            .to_string()
+            .starts_with("unknown rename rule `Title Case`, expected one of \"lowercase\""));
+    }
+
+    #[test]
+    fn strip_keyword_underscore() {
+        assert_eq!(super::strip_keyword_underscore("lambda_"), "lambda");
+        assert_eq!(super::strip_keyword_underscore("from_"), "from");
+        assert_eq!(super::strip_keyword_underscore("None_"), "None");
+        assert_eq!(super::strip_keyword_underscore("type_"), "type_");
+        assert_eq!(super::strip_keyword_underscore("lambda__"), "lambda__");
+        assert_eq!(super::strip_keyword_underscore("lambda"), "lambda");
+        assert_eq!(super::strip_keyword_underscore("_"), "_");
+    }
+
+    fn rule_named(name: &str) -> RenameRule {
+        RenameRule::from_lit(&syn::LitStr::new(name, proc_macro2::Span::call_site())).unwrap()
+    }
+}
@@ -0,0 +1,230 @@
+use proc_macro2::TokenStream;
+use syn::{
+    ext::IdentExt, spanned::Spanned, Data, DataStruct, DeriveInput, Fields, GenericArgument,
+    PathArguments, Type,
+};
+
+use crate::{
+    attr::ContainerAttrs,
+    dict::{dict_fields, error_context, fields_pattern, set_items, Conversion},
+    error::{unsupported_shape, Errors},
+};
+
+pub fn impl_py_dataclass(input: DeriveInput) -> syn::Result<TokenStream> {
+    // The name of the struct for which `PyDataclass` is being derived
+    let struct_ident = &input.ident;
+
+    // The attributes of the struct for which `PyDataclass` is being derived
+    let mut errors = Errors::default();
+    let container_attrs = ContainerAttrs::from_input(&input, &mut errors);
+
+    // The dataclass type is created only once, so it cannot depend on type parameters
+    if let Some(type_param) = input.generics.type_params().next() {
+        errors.push(syn::Error::new(
+            type_param.ident.span(),
+            format!("`PyDataclass` cannot be derived for `{struct_ident}` because it has type parameters, which would all share a single dataclass type"),
+        ));
+    }
+
+    // The fields of the struct together with their attributes and keys (which become the names of the dataclass fields)
+    let fields = match &input.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(fields),
+            ..
+        }) => dict_fields(
+            &fields.named,
+            container_attrs.rename_all,
+            container_attrs.strip_trailing_underscore,
+            &format!("struct '{struct_ident}'"),
+            None,
+            &mut errors,
+        ),
+        Data::Struct(DataStruct { fields, .. }) => {
+            let span = match fields {
+                Fields::Unit => struct_ident.span(),
+                _ => fields.span(),
+            };
+            errors.push(unsupported_shape(
+                span,
+                "PyDataclass",
+                struct_ident,
+                "a struct with named fields",
+            ));
+            return errors.finish(TokenStream::new());
+        }
+        Data::Enum(data) => {
+            errors.push(unsupported_shape(
+                data.enum_token.span,
+                "PyDataclass",
+                struct_ident,
+                "a struct with named fields",
+            ));
+            return errors.finish(TokenStream::new());
+        }
+        Data::Union(data) => {
+            errors.push(unsupported_shape(
+                data.union_token.span,
+                "PyDataclass",
+                struct_ident,
+                "a struct with named fields",
+            ));
+            return errors.finish(TokenStream::new());
+        }
+    };
+    for field in &fields {
+        if let Some(skip_if) = &field.attrs.skip_if {
+            errors.push(syn::Error::new_spanned(
+                skip_if,
+                "`skip_if` attribute is not supported for fields of a dataclass",
+            ));
+        }
+        if field.attrs.flatten {
+            errors.push(syn::Error::new(
+                field.ident.span(),
+                "`flatten` attribute is not supported for fields of a dataclass",
+            ));
+        }
+        if field.attrs.nested {
+            errors.push(syn::Error::new(
+                field.ident.span(),
+                "`nested` attribute is not supported for fields of a dataclass",
+            ));
+        }
+        if let Some(path) = &field.attrs.path {
+            errors.push(syn::Error::new(
+                path.span(),
+                "`path` attribute is not supported for fields of a dataclass",
+            ));
+        }
+        if field.attrs.default {
+            errors.push(syn::Error::new(
+                field.ident.span(),
+                "`default` attribute is not supported for fields of a dataclass",
+            ));
+        }
+    }
+    errors.finish(())?;
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // The names of the dataclass fields together with the expressions of their type annotations
+    let (keys, annotations): (Vec<_>, Vec<_>) = fields
+        .iter()
+        .filter(|field| !field.attrs.skip)
+        .map(|field| (&field.key, annotation(field.ty)))
+        .unzip();
+
+    // The insertion of all fields into the keyword arguments of the dataclass constructor
+    let pattern = fields_pattern(&fields);
+    let set_items = set_items(&fields, Conversion::Owned);
+
+    // The generated implementation
+    let vis = &input.vis;
+    let name = struct_ident.unraw().to_string();
+    let type_message = format!("Failed to create the dataclass type of '{struct_ident}'.");
+    let type_context = error_context(quote::quote! { #type_message });
+    let message = format!("Failed to convert '{struct_ident}' into a dataclass instance.");
+    let context = error_context(quote::quote! { #message });
+    Ok(quote::quote! {
+        #[automatically_derived]
+        impl #impl_generics #struct_ident #ty_generics #where_clause {
+            /// The Python dataclass type that mirrors this struct, which is created once and then reused.
+            #vis fn py_dataclass(py: ::pyo3::Python) -> ::pyo3::PyResult<&::pyo3::types::PyType> {
+                static DATACLASS: ::pyo3::sync::GILOnceCell<::pyo3::Py<::pyo3::types::PyType>> = ::pyo3::sync::GILOnceCell::new();
+                DATACLASS
+                    .get_or_try_init(py, || {
+                        let globals = ::pyo3::types::IntoPyDict::into_py_dict(
+                            [("typing", ::pyo3::types::PyModule::import(py, "typing")?)],
+                            py,
+                        );
+                        let fields = ::pyo3::types::PyList::empty(py);
+                        #(
+                            fields.append((#keys, py.eval(#annotations, Some(globals), None)?))?;
+                        )*
+                        ::pyo3::types::PyModule::import(py, "dataclasses")?
+                            .getattr("make_dataclass")?
+                            .call1((#name, fields))?
+                            .extract::<::pyo3::Py<::pyo3::types::PyType>>()
+                    })
+                    .map(|dataclass| dataclass.as_ref(py))
+                    .map_err(#type_context)
+            }
+
+            /// Convert into an instance of the Python dataclass type, returning an error if any of the fields fails to convert.
+            #vis fn try_into_py_dataclass(self, py: ::pyo3::Python) -> ::pyo3::PyResult<&::pyo3::PyAny> {
+                let kwargs = ::pyo3::types::PyDict::new(py);
+                let Self #pattern = self;
+                #(#set_items)*
+                Self::py_dataclass(py)?.call((), Some(kwargs)).map_err(#context)
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::pyo3::IntoPy<::pyo3::PyObject> for #struct_ident #ty_generics #where_clause {
+            fn into_py(self, py: ::pyo3::Python) -> ::pyo3::PyObject {
+                self.try_into_py_dataclass(py).expect(#message).into()
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::std::convert::TryFrom<#struct_ident #ty_generics> for ::pyo3::Py<::pyo3::PyAny> #where_clause {
+            type Error = ::pyo3::PyErr;
+
+            fn try_from(value: #struct_ident #ty_generics) -> ::pyo3::PyResult<Self> {
+                ::pyo3::Python::with_gil(|py| {
+                    value.try_into_py_dataclass(py).map(::std::convert::Into::into)
+                })
+            }
+        }
+    })
+}
+
+/// Python expression of the type annotation that corresponds to a Rust type, which is evaluated
+/// with the `typing` module in scope (types without a Python counterpart become `typing.Any`)
+fn annotation(ty: &Type) -> String {
+    match ty {
+        Type::Reference(reference) => annotation(&reference.elem),
+        Type::Paren(paren) => annotation(&paren.elem),
+        Type::Group(group) => annotation(&group.elem),
+        Type::Array(array) => format!("typing.List[{}]", annotation(&array.elem)),
+        Type::Slice(slice) => format!("typing.List[{}]", annotation(&slice.elem)),
+        Type::Tuple(tuple) if tuple.elems.is_empty() => "None".to_owned(),
+        Type::Tuple(tuple) => {
+            let elems: Vec<_> = tuple.elems.iter().map(annotation).collect();
+            format!("typing.Tuple[{}]", elems.join(", "))
+        }
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            let segment = type_path.path.segments.last().unwrap();
+            let args: Vec<_> = match &segment.arguments {
+                PathArguments::AngleBracketed(arguments) => arguments
+                    .args
+                    .iter()
+                    .filter_map(|arg| match arg {
+                        GenericArgument::Type(ty) => Some(annotation(ty)),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => Vec::new(),
+            };
+            match (segment.ident.to_string().as_str(), args.as_slice()) {
+                ("bool", []) => "bool".to_owned(),
+                (
+                    "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64"
+                    | "u128" | "usize",
+                    [],
+                ) => "int".to_owned(),
+                ("f32" | "f64", []) => "float".to_owned(),
+                ("String" | "str" | "char", []) => "str".to_owned(),
+                ("Option", [inner]) => format!("typing.Optional[{inner}]"),
+                ("Box" | "Rc" | "Arc", [inner]) => inner.clone(),
+                ("Vec" | "VecDeque" | "LinkedList", [elem]) => format!("typing.List[{elem}]"),
+                ("HashSet" | "BTreeSet", [elem]) => format!("typing.Set[{elem}]"),
+                ("HashMap" | "BTreeMap", [key, value]) => {
+                    format!("typing.Dict[{key}, {value}]")
+                }
+                _ => "typing.Any".to_owned(),
+            }
+        }
+        _ => "typing.Any".to_owned(),
+    }
+}
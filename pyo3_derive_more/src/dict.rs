@@ -1,44 +1,1188 @@
-use syn::{Data, DataStruct, DeriveInput, Fields};
+use std::collections::HashMap;
 
-pub fn impl_into_pydict(input: DeriveInput) -> proc_macro::TokenStream {
-    // The name of the struct for which `IntoPyDict` is being derived
-    let struct_ident = input.ident;
+use proc_macro2::TokenStream;
+use syn::{
+    ext::IdentExt, punctuated::Punctuated, spanned::Spanned, token::Comma, Data, DataEnum,
+    DataStruct, DeriveInput, Field, Fields, Ident, LitStr, Type,
+};
 
-    // The fields of the struct with named fields for which `IntoPyDict` is being derived
-    let named_fields = match &input.data {
-        Data::Struct(DataStruct {
-            fields: Fields::Named(fields),
-            ..
-        }) => &fields.named,
-        _ => panic!("Trait pyo3::types::IntoPyDict cannot be derived for {struct_ident} because it is not a struct with named fields."),
-    };
+use crate::{
+    attr::{ContainerAttrs, FieldAttrs, VariantAttrs},
+    bound::{with_bound, with_py_lifetime},
+    case::{strip_keyword_underscore, RenameRule},
+    error::{unsupported_shape, Errors},
+    nested::NestedType,
+};
+
+pub fn impl_into_pydict(input: DeriveInput) -> syn::Result<TokenStream> {
+    // The name of the struct or enum for which `IntoPyDict` is being derived
+    let ident = &input.ident;
 
-    // The names of the fields of the struct for which `IntoPyDict` is being derived
-    let field_names = named_fields.iter().map(|field| &field.ident);
+    // The fields (struct) or variants (enum) together with their attributes and keys
+    let (shape, extras, _) = DictShape::parse(&input, "IntoPyDict")?;
+
+    // The generics with `IntoPy<PyObject>` bounds for the type parameters of converted fields, and
+    // `TryIntoPyDict` bounds for the type parameters of flattened and nested fields and merged
+    // newtype variants
+    let generics = with_bound(
+        &input.generics,
+        shape.field_types(),
+        &syn::parse_quote!(::pyo3::IntoPy<::pyo3::PyObject>),
+    );
+    let generics = with_bound(
+        &generics,
+        shape.merged_types(),
+        &syn::parse_quote!(::pyo3_macros_more::TryIntoPyDict),
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    // The insertion of all fields into the dictionary
+    let body = insert_items(&shape, &extras, Conversion::Owned);
 
     // The generated implementation
-    quote::quote! {
+    let vis = &input.vis;
+    let message = format!("Failed to convert '{ident}' into a pyo3::types::PyDict.");
+    Ok(quote::quote! {
         #[automatically_derived]
-        impl ::pyo3::types::IntoPyDict for #struct_ident {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Convert into a `pyo3::types::PyDict`, returning an error if any of the items fails to convert.
+            #vis fn try_into_py_dict(self, py: ::pyo3::Python) -> ::pyo3::PyResult<&::pyo3::types::PyDict> {
+                let kwargs = ::pyo3::types::PyDict::new(py);
+                #body
+                Ok(kwargs)
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::pyo3::types::IntoPyDict for #ident #ty_generics #where_clause {
             fn into_py_dict(self, py: ::pyo3::Python) -> &::pyo3::types::PyDict {
+                self.try_into_py_dict(py).expect(#message)
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::pyo3_macros_more::TryIntoPyDict for #ident #ty_generics #where_clause {
+            fn try_into_py_dict(self, py: ::pyo3::Python) -> ::pyo3::PyResult<&::pyo3::types::PyDict> {
+                self.try_into_py_dict(py)
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::std::convert::TryFrom<#ident #ty_generics> for ::pyo3::Py<::pyo3::types::PyDict> #where_clause {
+            type Error = ::pyo3::PyErr;
+
+            fn try_from(value: #ident #ty_generics) -> ::pyo3::PyResult<Self> {
+                ::pyo3::Python::with_gil(|py| {
+                    value.try_into_py_dict(py).map(::std::convert::Into::into)
+                })
+            }
+        }
+    })
+}
+
+pub fn impl_to_pydict(input: DeriveInput) -> syn::Result<TokenStream> {
+    // The name of the struct or enum for which `ToPyDict` is being derived
+    let ident = &input.ident;
+
+    // The fields (struct) or variants (enum) together with their attributes and keys
+    let (shape, extras, _) = DictShape::parse(&input, "ToPyDict")?;
+
+    // The generics with `ToPyObject` bounds for the type parameters of converted fields, and
+    // `TryToPyDict` bounds for the type parameters of flattened and nested fields and merged
+    // newtype variants
+    let generics = with_bound(
+        &input.generics,
+        shape.field_types(),
+        &syn::parse_quote!(::pyo3::ToPyObject),
+    );
+    let generics = with_bound(
+        &generics,
+        shape.merged_types(),
+        &syn::parse_quote!(::pyo3_macros_more::TryToPyDict),
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    // The insertion of all fields into the dictionary
+    let body = insert_items(&shape, &extras, Conversion::Borrowed);
+
+    // The generated implementation
+    let vis = &input.vis;
+    let message = format!("Failed to convert '{ident}' into a pyo3::types::PyDict.");
+    Ok(quote::quote! {
+        #[automatically_derived]
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Convert into a `pyo3::types::PyDict` without consuming `self`, returning an error if any of the items fails to convert.
+            #vis fn try_to_py_dict<'__py>(&self, py: ::pyo3::Python<'__py>) -> ::pyo3::PyResult<&'__py ::pyo3::types::PyDict> {
                 let kwargs = ::pyo3::types::PyDict::new(py);
-                #(
-                    kwargs.set_item(::pyo3::intern!(py, stringify!(#field_names)), self.#field_names).expect(format!("Failed to convert field '{}' of struct '{}' into a pyo3::types::PyDict item.", stringify!(#field_names), stringify!(#struct_ident)).as_str());
-                )*
-                kwargs
+                #body
+                Ok(kwargs)
+            }
+
+            /// Convert into a `pyo3::types::PyDict` without consuming `self`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if any of the items fails to convert.
+            #vis fn to_py_dict<'__py>(&self, py: ::pyo3::Python<'__py>) -> &'__py ::pyo3::types::PyDict {
+                self.try_to_py_dict(py).expect(#message)
             }
         }
 
         #[automatically_derived]
-        impl ::std::convert::From<#struct_ident> for ::pyo3::Py<::pyo3::types::PyDict>
-        where
-            #struct_ident: ::pyo3::types::IntoPyDict,
-        {
-            fn from(value: #struct_ident) -> Self {
+        impl #impl_generics ::pyo3_macros_more::TryToPyDict for #ident #ty_generics #where_clause {
+            fn try_to_py_dict<'__py>(&self, py: ::pyo3::Python<'__py>) -> ::pyo3::PyResult<&'__py ::pyo3::types::PyDict> {
+                self.try_to_py_dict(py)
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::pyo3::ToPyObject for #ident #ty_generics #where_clause {
+            fn to_object(&self, py: ::pyo3::Python) -> ::pyo3::PyObject {
+                self.to_py_dict(py).into()
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::std::convert::TryFrom<&#ident #ty_generics> for ::pyo3::Py<::pyo3::types::PyDict> #where_clause {
+            type Error = ::pyo3::PyErr;
+
+            fn try_from(value: &#ident #ty_generics) -> ::pyo3::PyResult<Self> {
                 ::pyo3::Python::with_gil(|py| {
-                    ::pyo3::types::IntoPyDict::into_py_dict(value, py).into()
+                    value.try_to_py_dict(py).map(::std::convert::Into::into)
                 })
             }
         }
-    }.into()
+    })
+}
+
+/// Insertion of all fields (struct) or the variant with its fields (enum) into `kwargs`, followed
+/// by the additional items that are computed before `self` is destructured
+pub fn insert_items(
+    shape: &DictShape,
+    extras: &[DictExtra],
+    conversion: Conversion,
+) -> TokenStream {
+    let receiver = conversion.reference(&Ident::new("self", proc_macro2::Span::call_site()));
+    let compute_extras = extras.iter().map(|extra| {
+        let DictExtra { with, binding, .. } = extra;
+        quote::quote! {
+            let #binding = ::pyo3::IntoPy::<::pyo3::PyObject>::into_py(#with(#receiver), py);
+        }
+    });
+    let set_extras = extras.iter().map(|extra| {
+        let DictExtra {
+            key,
+            binding,
+            owner,
+            ..
+        } = extra;
+        let message = format!(
+            "Failed to convert extra item '{}' of {owner} into a pyo3::types::PyDict item.",
+            key.value()
+        );
+        let context = error_context(quote::quote! { #message });
+        let collision_message = format!(
+            "Key '{}' of extra item of {owner} collides with another key.",
+            key.value()
+        );
+        quote::quote! {
+            if kwargs.contains(::pyo3::intern!(py, #key)).map_err(#context)? {
+                return Err(::pyo3::exceptions::PyKeyError::new_err(#collision_message));
+            }
+            kwargs.set_item(::pyo3::intern!(py, #key), #binding).map_err(#context)?;
+        }
+    });
+    let body = match shape {
+        DictShape::Struct(fields) => {
+            let pattern = fields_pattern(fields);
+            let set_items = set_items(fields, conversion);
+            quote::quote! {
+                let Self #pattern = self;
+                #(#set_items)*
+            }
+        }
+        DictShape::Enum {
+            tag,
+            content,
+            variants,
+        } => {
+            let arms = variants
+                .iter()
+                .map(|variant| variant_arm(variant, tag, content.as_ref(), conversion));
+            quote::quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+    };
+    quote::quote! {
+        #(#compute_extras)*
+        #body
+        #(#set_extras)*
+    }
+}
+
+/// Generates the match arm that inserts the tag and the items of a variant into `kwargs`
+fn variant_arm(
+    variant: &DictVariant,
+    tag: &LitStr,
+    content: Option<&LitStr>,
+    conversion: Conversion,
+) -> TokenStream {
+    let DictVariant {
+        ident: variant_ident,
+        name,
+        kind,
+        ..
+    } = variant;
+    let tag_message = format!(
+        "Failed to convert the tag of {} into a pyo3::types::PyDict item.",
+        variant.owner
+    );
+    let tag_context = error_context(quote::quote! { #tag_message });
+    let set_tag = quote::quote! {
+        kwargs.set_item(::pyo3::intern!(py, #tag), ::pyo3::intern!(py, #name)).map_err(#tag_context)?;
+    };
+    let content_message = format!(
+        "Failed to convert the content of {} into a pyo3::types::PyDict item.",
+        variant.owner
+    );
+    let content_context = error_context(quote::quote! { #content_message });
+    match (kind, content) {
+        (VariantKind::Unit, _) => quote::quote! {
+            Self::#variant_ident => {
+                #set_tag
+            }
+        },
+        (VariantKind::Named(fields), None) => {
+            let pattern = fields_pattern(fields);
+            let set_items = set_items(fields, conversion);
+            quote::quote! {
+                Self::#variant_ident #pattern => {
+                    #set_tag
+                    #(#set_items)*
+                }
+            }
+        }
+        (VariantKind::Named(fields), Some(content)) => {
+            let pattern = fields_pattern(fields);
+            let set_items = set_items(fields, conversion);
+            quote::quote! {
+                Self::#variant_ident #pattern => {
+                    #set_tag
+                    let content = {
+                        let kwargs = ::pyo3::types::PyDict::new(py);
+                        #(#set_items)*
+                        kwargs
+                    };
+                    kwargs.set_item(::pyo3::intern!(py, #content), content).map_err(#content_context)?;
+                }
+            }
+        }
+        (VariantKind::Unnamed(_), None) => {
+            let items = conversion.items(&quote::format_ident!("__field0"), &content_context);
+            let collision_message = format!(
+                "Key '{{}}' of {} collides with the tag key '{}'.",
+                variant.owner,
+                tag.value()
+            );
+            quote::quote! {
+                Self::#variant_ident(__field0) => {
+                    #set_tag
+                    for (key, value) in #items {
+                        if key.eq(::pyo3::intern!(py, #tag)).map_err(#content_context)? {
+                            return Err(::pyo3::exceptions::PyKeyError::new_err(format!(#collision_message, key)));
+                        }
+                        kwargs.set_item(key, value).map_err(#content_context)?;
+                    }
+                }
+            }
+        }
+        (VariantKind::Unnamed(types), Some(content)) => {
+            let bindings: Vec<_> = (0..types.len())
+                .map(|index| quote::format_ident!("__field{index}"))
+                .collect();
+            let value = if bindings.len() == 1 {
+                quote::quote! { #(#bindings)* }
+            } else {
+                quote::quote! { (#(#bindings,)*) }
+            };
+            let value = conversion.value(&value);
+            quote::quote! {
+                Self::#variant_ident(#(#bindings),*) => {
+                    #set_tag
+                    kwargs.set_item(::pyo3::intern!(py, #content), #value).map_err(#content_context)?;
+                }
+            }
+        }
+    }
+}
+
+pub fn impl_from_pydict(input: DeriveInput) -> syn::Result<TokenStream> {
+    // The name of the struct or enum for which `FromPyDict` is being derived
+    let ident = &input.ident;
+
+    // The fields (struct) or variants (enum) together with their attributes and keys
+    let (shape, _, _) = DictShape::parse(&input, "FromPyDict")?;
+
+    // The generics extended by the lifetime of the dictionary, with `FromPyObject` bounds for
+    // the type parameters of extracted fields
+    let generics = with_bound(
+        &input.generics,
+        shape.field_types().into_iter().chain(shape.merged_types()),
+        &syn::parse_quote!(::pyo3::FromPyObject<'__py>),
+    );
+    let generics = with_py_lifetime(&generics, &input.generics);
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    // The extraction of all fields from the dictionary
+    let body = match &shape {
+        DictShape::Struct(fields) => {
+            let constructor = fields_constructor(fields);
+            quote::quote! {
+                Ok(Self #constructor)
+            }
+        }
+        DictShape::Enum {
+            tag,
+            content,
+            variants,
+        } => {
+            let names = variants.iter().map(|variant| &variant.name);
+            let arms = variants.iter().map(|variant| {
+                let DictVariant {
+                    ident: variant_ident,
+                    name,
+                    kind,
+                    owner,
+                } = variant;
+                let content_message = content.as_ref().map(|content| {
+                    format!(
+                        "Failed to extract the content '{}' of {owner} from a pyo3::types::PyDict item.",
+                        content.value()
+                    )
+                });
+                let missing_content_message = content.as_ref().map(|content| {
+                    format!(
+                        "Missing key '{}' required by the content of {owner}.",
+                        content.value()
+                    )
+                });
+                let extract_content = |ty: TokenStream| {
+                    let context = error_context(quote::quote! { #content_message });
+                    quote::quote! {
+                        match dict.get_item(::pyo3::intern!(py, #content))? {
+                            Some(content) => content.extract::<#ty>().map_err(#context)?,
+                            None => return Err(::pyo3::exceptions::PyKeyError::new_err(#missing_content_message)),
+                        }
+                    }
+                };
+                let constructor = match (kind, content) {
+                    (VariantKind::Unit, _) => quote::quote! {
+                        Self::#variant_ident
+                    },
+                    (VariantKind::Named(fields), None) => {
+                        let constructor = fields_constructor(fields);
+                        quote::quote! {
+                            Self::#variant_ident #constructor
+                        }
+                    }
+                    (VariantKind::Named(fields), Some(_)) => {
+                        let constructor = fields_constructor(fields);
+                        let extract_content =
+                            extract_content(quote::quote! { &::pyo3::types::PyDict });
+                        quote::quote! {{
+                            let dict = #extract_content;
+                            Self::#variant_ident #constructor
+                        }}
+                    }
+                    (VariantKind::Unnamed(types), None) => {
+                        let message =
+                            format!("Failed to extract {owner} from a pyo3::types::PyDict.");
+                        let context = error_context(quote::quote! { #message });
+                        let ty = &types[0];
+                        quote::quote! {{
+                            let content = dict.copy()?;
+                            content.del_item(::pyo3::intern!(py, #tag))?;
+                            Self::#variant_ident(content.extract::<#ty>().map_err(#context)?)
+                        }}
+                    }
+                    (VariantKind::Unnamed(types), Some(_)) => {
+                        let bindings: Vec<_> = (0..types.len())
+                            .map(|index| quote::format_ident!("__field{index}"))
+                            .collect();
+                        if types.len() == 1 {
+                            let extract_content = extract_content(quote::quote! { #(#types)* });
+                            quote::quote! {
+                                Self::#variant_ident(#extract_content)
+                            }
+                        } else {
+                            let extract_content =
+                                extract_content(quote::quote! { (#(#types,)*) });
+                            quote::quote! {{
+                                let (#(#bindings,)*) = #extract_content;
+                                Self::#variant_ident(#(#bindings),*)
+                            }}
+                        }
+                    }
+                };
+                quote::quote! {
+                    #name => Ok(#constructor),
+                }
+            });
+            let missing_tag_message = format!(
+                "Missing key '{}' required by the tag of enum '{ident}'.",
+                tag.value()
+            );
+            let tag_context = error_context(quote::quote! {
+                format!("Failed to extract the tag '{}' of enum '{}' from a pyo3::types::PyDict item.", #tag, stringify!(#ident))
+            });
+            let expected = names
+                .map(|name| format!("'{}'", name.value()))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let unknown_message =
+                format!("Unknown variant '{{}}' of enum '{ident}', expected one of {expected}.");
+            quote::quote! {
+                let tag = match dict.get_item(::pyo3::intern!(py, #tag))? {
+                    Some(tag) => tag.extract::<&str>().map_err(#tag_context)?,
+                    None => return Err(::pyo3::exceptions::PyKeyError::new_err(#missing_tag_message)),
+                };
+                match tag {
+                    #(#arms)*
+                    tag => Err(::pyo3::exceptions::PyValueError::new_err(format!(#unknown_message, tag))),
+                }
+            }
+        }
+    };
+
+    // The extraction from an owned dictionary, which is possible only without lifetimes
+    let try_from_py = if input.generics.lifetimes().next().is_none() {
+        let generics = with_bound(
+            &input.generics,
+            shape.field_types().into_iter().chain(shape.merged_types()),
+            &syn::parse_quote!(for<'__py> ::pyo3::FromPyObject<'__py>),
+        );
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        Some(quote::quote! {
+            #[automatically_derived]
+            impl #impl_generics ::std::convert::TryFrom<::pyo3::Py<::pyo3::types::PyDict>> for #ident #ty_generics #where_clause {
+                type Error = ::pyo3::PyErr;
+
+                fn try_from(value: ::pyo3::Py<::pyo3::types::PyDict>) -> ::pyo3::PyResult<Self> {
+                    ::pyo3::Python::with_gil(|py| {
+                        <Self as ::std::convert::TryFrom<&::pyo3::types::PyDict>>::try_from(value.as_ref(py))
+                    })
+                }
+            }
+        })
+    } else {
+        None
+    };
+
+    // The generated implementation
+    Ok(quote::quote! {
+        #[automatically_derived]
+        impl #impl_generics ::std::convert::TryFrom<&'__py ::pyo3::types::PyDict> for #ident #ty_generics #where_clause {
+            type Error = ::pyo3::PyErr;
+
+            fn try_from(dict: &'__py ::pyo3::types::PyDict) -> ::pyo3::PyResult<Self> {
+                let py = dict.py();
+                #body
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::pyo3::FromPyObject<'__py> for #ident #ty_generics #where_clause {
+            fn extract(ob: &'__py ::pyo3::PyAny) -> ::pyo3::PyResult<Self> {
+                <Self as ::std::convert::TryFrom<&'__py ::pyo3::types::PyDict>>::try_from(ob.downcast()?)
+            }
+        }
+
+        #try_from_py
+    })
+}
+
+/// The fields of a struct or the variants of an enum that is converted from/into a dictionary
+pub enum DictShape<'a> {
+    /// Struct with named fields
+    Struct(Vec<DictField<'a>>),
+    /// Enum with a tag that holds the name of the variant (and optionally a key for its content)
+    Enum {
+        tag: LitStr,
+        content: Option<LitStr>,
+        variants: Vec<DictVariant<'a>>,
+    },
+}
+
+/// A variant of an enum together with its name
+pub struct DictVariant<'a> {
+    ident: &'a Ident,
+    name: LitStr,
+    kind: VariantKind<'a>,
+    /// Description of the variant used in error messages
+    owner: String,
+}
+
+/// The fields of an enum variant
+pub enum VariantKind<'a> {
+    Unit,
+    Named(Vec<DictField<'a>>),
+    Unnamed(Vec<&'a Type>),
+}
+
+/// An additional item whose value is computed by a method
+pub struct DictExtra {
+    key: LitStr,
+    with: syn::ExprPath,
+    /// The local variable that holds the value of the item
+    binding: Ident,
+    /// Description of the struct or enum that owns the item used in error messages
+    owner: String,
+}
+
+/// A named field together with its attributes and key
+pub struct DictField<'a> {
+    pub ident: &'a Ident,
+    pub ty: &'a Type,
+    pub attrs: FieldAttrs,
+    pub key: LitStr,
+    /// The local variable that holds the value of the field
+    pub binding: Ident,
+    /// Description of the struct or variant that owns the field used in error messages
+    pub owner: String,
+}
+
+impl<'a> DictShape<'a> {
+    /// Parse the attributes of a struct or enum for which `trait_name` is being derived, together
+    /// with its additional items and the attributes of the struct or enum
+    pub fn parse(
+        input: &'a DeriveInput,
+        trait_name: &str,
+    ) -> syn::Result<(Self, Vec<DictExtra>, ContainerAttrs)> {
+        let ident = &input.ident;
+        let mut errors = Errors::default();
+        let mut container_attrs = ContainerAttrs::from_input(input, &mut errors);
+        let shape = match &input.data {
+            Data::Struct(DataStruct {
+                fields: Fields::Named(fields),
+                ..
+            }) => {
+                let fields = dict_fields(
+                    &fields.named,
+                    container_attrs.rename_all,
+                    container_attrs.strip_trailing_underscore,
+                    &format!("struct '{ident}'"),
+                    None,
+                    &mut errors,
+                );
+                reject_attribute_fields(&fields, &mut errors);
+                Self::Struct(fields)
+            }
+            Data::Enum(DataEnum { variants, .. }) => {
+                let tag = container_attrs.tag.clone().unwrap_or_else(|| {
+                    errors.push(syn::Error::new(
+                        ident.span(),
+                        format!("enum `{ident}` requires the `#[pyo3_more(tag = \"key\")]` attribute with the key that holds the name of the variant"),
+                    ));
+                    LitStr::new("", ident.span())
+                });
+                if let Some(content) = container_attrs
+                    .content
+                    .as_ref()
+                    .filter(|content| content.value() == tag.value())
+                {
+                    errors.push(syn::Error::new(
+                        content.span(),
+                        "the `content` key must differ from the `tag` key",
+                    ));
+                }
+
+                let mut dict_variants = Vec::with_capacity(variants.len());
+                let mut names = HashMap::with_capacity(variants.len());
+                for variant in variants {
+                    let variant_attrs = VariantAttrs::from_attrs(&variant.attrs, &mut errors);
+                    let variant_ident = &variant.ident;
+                    for alias in &variant_attrs.aliases {
+                        errors.push(syn::Error::new(
+                            alias.span(),
+                            "`alias` attribute is supported only for variants of `PyEnum` and `PyStrEnum` enums",
+                        ));
+                    }
+                    let name =
+                        variant_name(variant_ident, &variant_attrs, container_attrs.rename_all);
+                    if let Some(other_variant_ident) = names.insert(name.value(), variant_ident) {
+                        errors.push(syn::Error::new(
+                            name.span(),
+                            format!(
+                                "name \"{}\" of variant `{variant_ident}` is already used by variant `{other_variant_ident}`",
+                                name.value()
+                            ),
+                        ));
+                    }
+
+                    let owner = format!("variant '{ident}::{variant_ident}'");
+                    let kind = match &variant.fields {
+                        Fields::Unit => VariantKind::Unit,
+                        Fields::Named(fields) => {
+                            // Fields stored next to the tag must not collide with it
+                            let reserved = if container_attrs.content.is_none() {
+                                Some(&tag)
+                            } else {
+                                None
+                            };
+                            let fields = dict_fields(
+                                &fields.named,
+                                variant_attrs.rename_all,
+                                container_attrs.strip_trailing_underscore,
+                                &owner,
+                                reserved,
+                                &mut errors,
+                            );
+                            reject_attribute_fields(&fields, &mut errors);
+                            VariantKind::Named(fields)
+                        }
+                        Fields::Unnamed(fields) => {
+                            if container_attrs.content.is_none() && fields.unnamed.len() != 1 {
+                                errors.push(syn::Error::new(
+                                    fields.span(),
+                                    "tuple variants require the `#[pyo3_more(content = \"key\")]` attribute, unless they contain a single field that is merged into the dictionary",
+                                ));
+                            }
+                            VariantKind::Unnamed(
+                                fields.unnamed.iter().map(|field| &field.ty).collect(),
+                            )
+                        }
+                    };
+                    dict_variants.push(DictVariant {
+                        ident: variant_ident,
+                        name,
+                        kind,
+                        owner,
+                    });
+                }
+                Self::Enum {
+                    tag,
+                    content: container_attrs.content.clone(),
+                    variants: dict_variants,
+                }
+            }
+            Data::Struct(DataStruct { fields, .. }) => {
+                let span = match fields {
+                    Fields::Unit => ident.span(),
+                    _ => fields.span(),
+                };
+                errors.push(unsupported_shape(
+                    span,
+                    trait_name,
+                    ident,
+                    "a struct with named fields or an enum",
+                ));
+                Self::Struct(Vec::new())
+            }
+            Data::Union(data) => {
+                errors.push(unsupported_shape(
+                    data.union_token.span,
+                    trait_name,
+                    ident,
+                    "a struct with named fields or an enum",
+                ));
+                Self::Struct(Vec::new())
+            }
+        };
+
+        // The additional items must not collide with the keys that are known at compile time
+        let owner = match &shape {
+            Self::Struct(_) => format!("struct '{ident}'"),
+            Self::Enum { .. } => format!("enum '{ident}'"),
+        };
+        let mut keys: HashMap<_, _> = shape
+            .static_keys()
+            .into_iter()
+            .map(|key| (key.value(), "a field or the tag"))
+            .collect();
+        let mut extras = Vec::with_capacity(container_attrs.extras.len());
+        for (index, extra) in std::mem::take(&mut container_attrs.extras)
+            .into_iter()
+            .enumerate()
+        {
+            if let Some(other) = keys.insert(extra.key.value(), "another extra item") {
+                errors.push(syn::Error::new(
+                    extra.key.span(),
+                    format!(
+                        "key \"{}\" of extra item is already used by {other}",
+                        extra.key.value()
+                    ),
+                ));
+            }
+            extras.push(DictExtra {
+                key: extra.key,
+                with: extra.with,
+                binding: quote::format_ident!("__extra{index}"),
+                owner: owner.clone(),
+            });
+        }
+        errors.finish((shape, extras, container_attrs))
+    }
+
+    /// The keys of the dictionary that are known at compile time
+    fn static_keys(&self) -> Vec<&LitStr> {
+        fn field_keys<'b>(fields: &'b [DictField]) -> impl Iterator<Item = &'b LitStr> {
+            fields
+                .iter()
+                .filter(|field| !field.attrs.skip && !field.attrs.flatten)
+                .map(|field| &field.key)
+        }
+        match self {
+            Self::Struct(fields) => field_keys(fields).collect(),
+            Self::Enum {
+                tag,
+                content: Some(content),
+                ..
+            } => vec![tag, content],
+            Self::Enum {
+                tag,
+                content: None,
+                variants,
+            } => std::iter::once(tag)
+                .chain(variants.iter().flat_map(|variant| match &variant.kind {
+                    VariantKind::Named(fields) => field_keys(fields).collect(),
+                    _ => Vec::new(),
+                }))
+                .collect(),
+        }
+    }
+
+    /// The types of all fields that are converted as values of the dictionary (and the keys of
+    /// maps in nested fields)
+    pub fn field_types(&self) -> Vec<&'a Type> {
+        match self {
+            Self::Struct(fields) => converted_types(fields),
+            Self::Enum {
+                content, variants, ..
+            } => variants
+                .iter()
+                .flat_map(|variant| match &variant.kind {
+                    VariantKind::Unit => Vec::new(),
+                    VariantKind::Named(fields) => converted_types(fields),
+                    VariantKind::Unnamed(types) if content.is_some() => types.clone(),
+                    VariantKind::Unnamed(_) => Vec::new(),
+                })
+                .collect(),
+        }
+    }
+
+    /// The types of all flattened fields and newtype variants whose dictionary is merged into
+    /// the dictionary, as well as the types that are converted into nested dictionaries
+    pub fn merged_types(&self) -> Vec<&'a Type> {
+        match self {
+            Self::Struct(fields) => dict_types(fields),
+            Self::Enum {
+                content, variants, ..
+            } => variants
+                .iter()
+                .flat_map(|variant| match &variant.kind {
+                    VariantKind::Unit => Vec::new(),
+                    VariantKind::Named(fields) => dict_types(fields),
+                    VariantKind::Unnamed(types) if content.is_none() => vec![types[0]],
+                    VariantKind::Unnamed(_) => Vec::new(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// The types of fields that are converted by their own conversions, i.e. fields that are neither
+/// skipped, flattened nor converted `with` a custom module (for nested fields, the keys of their maps)
+pub fn converted_types<'a, 'b>(fields: impl IntoIterator<Item = &'b DictField<'a>>) -> Vec<&'a Type>
+where
+    'a: 'b,
+{
+    fields
+        .into_iter()
+        .filter(|field| !field.attrs.skip && !field.attrs.flatten && field.attrs.with.is_none())
+        .flat_map(|field| {
+            if field.attrs.nested {
+                NestedType::parse(field.ty).key_types()
+            } else {
+                vec![field.ty]
+            }
+        })
+        .collect()
+}
+
+/// The types of flattened fields and the types that nested fields convert into dictionaries
+fn dict_types<'a>(fields: &[DictField<'a>]) -> Vec<&'a Type> {
+    fields
+        .iter()
+        .filter(|field| !field.attrs.skip)
+        .flat_map(|field| {
+            if field.attrs.flatten {
+                vec![field.ty]
+            } else if field.attrs.nested {
+                NestedType::parse(field.ty).dict_types()
+            } else {
+                Vec::new()
+            }
+        })
+        .collect()
+}
+
+/// Parse the attributes of named fields into fields with unique keys (that differ from the `reserved` key)
+pub fn dict_fields<'a>(
+    named_fields: &'a Punctuated<Field, Comma>,
+    rename_all: Option<RenameRule>,
+    strip_trailing_underscore: bool,
+    owner: &str,
+    reserved: Option<&LitStr>,
+    errors: &mut Errors,
+) -> Vec<DictField<'a>> {
+    let mut dict_fields = Vec::with_capacity(named_fields.len());
+    let mut keys = HashMap::with_capacity(named_fields.len());
+    for field in named_fields {
+        let field_attrs = FieldAttrs::from_attrs(&field.attrs, errors);
+        let field_ident = field.ident.as_ref().unwrap();
+        let key = field_key(
+            field_ident,
+            &field_attrs,
+            rename_all,
+            strip_trailing_underscore,
+        );
+        if field_attrs.flatten {
+            // The keys of flattened fields are known only at runtime
+            if let Some(rename) = &field_attrs.rename {
+                errors.push(syn::Error::new(
+                    rename.span(),
+                    "`flatten` cannot be combined with `rename`",
+                ));
+            }
+            if let Some(skip_if) = &field_attrs.skip_if {
+                errors.push(syn::Error::new_spanned(
+                    skip_if,
+                    "`flatten` cannot be combined with `skip_if`",
+                ));
+            }
+            if field_attrs.skip {
+                errors.push(syn::Error::new(
+                    field_ident.span(),
+                    "`flatten` cannot be combined with `skip`",
+                ));
+            }
+            if let Some(with) = &field_attrs.with {
+                errors.push(syn::Error::new_spanned(
+                    with,
+                    "`flatten` cannot be combined with `with`",
+                ));
+            }
+            if field_attrs.nested {
+                errors.push(syn::Error::new(
+                    field_ident.span(),
+                    "`flatten` cannot be combined with `nested`",
+                ));
+            }
+        } else if !field_attrs.skip {
+            if let Some(other_field_ident) = keys.insert(key.value(), field_ident) {
+                errors.push(syn::Error::new(
+                    key.span(),
+                    format!(
+                        "key \"{}\" of field `{field_ident}` is already used by field `{other_field_ident}`",
+                        key.value()
+                    ),
+                ));
+            }
+            if let Some(reserved) = reserved.filter(|reserved| reserved.value() == key.value()) {
+                errors.push(syn::Error::new(
+                    key.span(),
+                    format!(
+                        "key \"{}\" of field `{field_ident}` is already used by the tag",
+                        reserved.value()
+                    ),
+                ));
+            }
+        }
+        if let (Some(with), true) = (&field_attrs.with, field_attrs.nested) {
+            errors.push(syn::Error::new_spanned(
+                with,
+                "`nested` cannot be combined with `with`",
+            ));
+        }
+        dict_fields.push(DictField {
+            ident: field_ident,
+            ty: &field.ty,
+            attrs: field_attrs,
+            key,
+            binding: quote::format_ident!("__field_{}", field_ident.unraw()),
+            owner: owner.to_owned(),
+        });
+    }
+    dict_fields
+}
+
+/// Report the attributes that are supported only for fields stored in attributes of an object
+fn reject_attribute_fields(fields: &[DictField], errors: &mut Errors) {
+    for field in fields {
+        if let Some(path) = &field.attrs.path {
+            errors.push(syn::Error::new(
+                path.span(),
+                "`path` attribute is supported only for fields of `FromPyAttrs` and `ApplyToPyObject` structs",
+            ));
+        }
+        if field.attrs.default {
+            errors.push(syn::Error::new(
+                field.ident.span(),
+                "`default` attribute is supported only for fields of `FromPyAttrs` and `ApplyToPyObject` structs",
+            ));
+        }
+    }
+}
+
+/// The name of a variant, i.e. its identifier without the `r#` prefix after applying the `rename`
+/// and `rename_all` attributes
+pub fn variant_name(
+    variant_ident: &Ident,
+    variant_attrs: &VariantAttrs,
+    rename_all: Option<RenameRule>,
+) -> LitStr {
+    match (&variant_attrs.rename, rename_all) {
+        (Some(rename), _) => rename.clone(),
+        (None, Some(rename_all)) => LitStr::new(
+            &rename_all.apply_to_variant(&variant_ident.unraw().to_string()),
+            variant_ident.span(),
+        ),
+        (None, None) => LitStr::new(&variant_ident.unraw().to_string(), variant_ident.span()),
+    }
+}
+
+/// The key of a field, i.e. its name without the `r#` prefix after applying the `rename`,
+/// `rename_all` and `strip_trailing_underscore` attributes
+fn field_key(
+    field_ident: &Ident,
+    field_attrs: &FieldAttrs,
+    rename_all: Option<RenameRule>,
+    strip_trailing_underscore: bool,
+) -> LitStr {
+    if let Some(rename) = &field_attrs.rename {
+        return rename.clone();
+    }
+    let mut name = field_ident.unraw().to_string();
+    if strip_trailing_underscore {
+        name = strip_keyword_underscore(&name).to_owned();
+    }
+    let key = match rename_all {
+        Some(rename_all) => rename_all.apply_to_field(&name),
+        None => name,
+    };
+    LitStr::new(&key, field_ident.span())
+}
+
+/// Pattern that binds the fields that are not skipped to their local variables, i.e. `{ field: binding, .. }`
+pub fn fields_pattern(fields: &[DictField]) -> TokenStream {
+    let (idents, bindings): (Vec<_>, Vec<_>) = fields
+        .iter()
+        .filter(|field| !field.attrs.skip)
+        .map(|field| (field.ident, &field.binding))
+        .unzip();
+    quote::quote! {
+        { #(#idents: #bindings,)* .. }
+    }
+}
+
+/// Insertion of the fields that are not skipped from their local variables into `kwargs`, where
+/// the items of flattened fields are merged last so that their collisions with any other key
+/// are detected
+pub fn set_items(fields: &[DictField], conversion: Conversion) -> Vec<TokenStream> {
+    let (flattened, nested): (Vec<_>, Vec<_>) = fields
+        .iter()
+        .filter(|field| !field.attrs.skip)
+        .partition(|field| field.attrs.flatten);
+    nested
+        .into_iter()
+        .chain(flattened)
+        .map(|field| {
+            let DictField {
+                ident: field_ident,
+                key,
+                binding,
+                owner,
+                ..
+            } = field;
+            let message = format!(
+                "Failed to convert field '{field_ident}' of {owner} into a pyo3::types::PyDict item."
+            );
+            let context = error_context(quote::quote! { #message });
+            if field.attrs.flatten {
+                let collision_message = format!(
+                    "Key '{{}}' of flattened field '{field_ident}' of {owner} collides with another key."
+                );
+                let items = conversion.items(binding, &context);
+                return quote::quote! {
+                    for (key, value) in #items {
+                        if kwargs.contains(key).map_err(#context)? {
+                            return Err(::pyo3::exceptions::PyKeyError::new_err(format!(#collision_message, key)));
+                        }
+                        kwargs.set_item(key, value).map_err(#context)?;
+                    }
+                };
+            }
+            let reference = conversion.reference(binding);
+            let value = match (&field.attrs.with, conversion) {
+                (Some(with), _) => quote::quote! {
+                    #with::to_py(#reference, py).map_err(#context)?
+                },
+                (None, _) if field.attrs.nested => NestedType::parse(field.ty).conversion(
+                    &quote::quote! { #binding },
+                    conversion,
+                    &context,
+                ),
+                (None, _) => conversion.value(binding),
+            };
+            let set_item = quote::quote! {
+                kwargs.set_item(::pyo3::intern!(py, #key), #value).map_err(#context)?;
+            };
+            match &field.attrs.skip_if {
+                Some(skip_if) => quote::quote! {
+                    if !#skip_if(#reference) {
+                        #set_item
+                    }
+                },
+                None => set_item,
+            }
+        })
+        .collect()
+}
+
+/// Whether the fields are converted from owned values or from references
+#[derive(Clone, Copy)]
+pub enum Conversion {
+    /// `pyo3::IntoPy<pyo3::PyObject>` and `pyo3_macros_more::TryIntoPyDict` consuming `self`
+    Owned,
+    /// `pyo3::ToPyObject` and `pyo3_macros_more::TryToPyDict` borrowing `&self`
+    Borrowed,
+}
+
+impl Conversion {
+    /// The conversion of a field into a `pyo3::PyObject`
+    pub fn value(self, binding: &impl quote::ToTokens) -> TokenStream {
+        match self {
+            Self::Owned => quote::quote! {
+                ::pyo3::IntoPy::<::pyo3::PyObject>::into_py(#binding, py)
+            },
+            Self::Borrowed => quote::quote! {
+                ::pyo3::ToPyObject::to_object(&#binding, py)
+            },
+        }
+    }
+
+    /// The conversion of a field into a `&pyo3::types::PyDict`, which propagates its error with `?`
+    /// after wrapping it with `context`
+    pub fn items(self, binding: &impl quote::ToTokens, context: &TokenStream) -> TokenStream {
+        match self {
+            Self::Owned => quote::quote! {
+                ::pyo3_macros_more::TryIntoPyDict::try_into_py_dict(#binding, py).map_err(#context)?
+            },
+            Self::Borrowed => quote::quote! {
+                ::pyo3_macros_more::TryToPyDict::try_to_py_dict(#binding, py).map_err(#context)?
+            },
+        }
+    }
+
+    /// A reference to a field
+    fn reference(self, binding: &Ident) -> TokenStream {
+        match self {
+            Self::Owned => quote::quote! { &#binding },
+            Self::Borrowed => quote::quote! { #binding },
+        }
+    }
+}
+
+/// Construction of the fields from the items of `dict`, i.e. `{ field: value, ... }`
+fn fields_constructor(fields: &[DictField]) -> TokenStream {
+    let field_idents = fields.iter().map(|field| field.ident);
+    let extract_items = fields.iter().map(|field| {
+        let DictField {
+            ident: field_ident,
+            ty: field_ty,
+            key,
+            owner,
+            ..
+        } = field;
+        if field.attrs.skip {
+            return quote::quote! { ::std::default::Default::default() };
+        }
+        if field.attrs.flatten {
+            let message = format!(
+                "Failed to extract flattened field '{field_ident}' of {owner} from a pyo3::types::PyDict."
+            );
+            let context = error_context(quote::quote! { #message });
+            return quote::quote! {
+                dict.extract::<#field_ty>().map_err(#context)?
+            };
+        }
+        let missing = if is_option(field_ty) || field.attrs.skip_if.is_some() {
+            quote::quote! { ::std::default::Default::default() }
+        } else {
+            let message = format!(
+                "Missing key '{}' required by field '{field_ident}' of {owner}.",
+                key.value()
+            );
+            quote::quote! {
+                return Err(::pyo3::exceptions::PyKeyError::new_err(#message))
+            }
+        };
+        let message = format!(
+            "Failed to extract key '{}' of {owner} from a pyo3::types::PyDict item.",
+            key.value()
+        );
+        let context = error_context(quote::quote! { #message });
+        let extract = match &field.attrs.with {
+            Some(with) => quote::quote! { #with::from_py(value) },
+            None => quote::quote! { value.extract::<#field_ty>() },
+        };
+        quote::quote! {
+            match dict.get_item(::pyo3::intern!(py, #key))? {
+                Some(value) => #extract.map_err(#context)?,
+                None => #missing,
+            }
+        }
+    });
+    quote::quote! {
+        {
+            #(
+                #field_idents: #extract_items,
+            )*
+        }
+    }
+}
+
+/// Whether the type is syntactically an `Option<T>`
+pub fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => type_path
+            .path
+            .segments
+            .last()
+            .map_or(false, |segment| segment.ident == "Option"),
+        _ => false,
+    }
+}
+
+/// Closure that wraps a `pyo3::PyErr` into an error of the same type with the given message,
+/// while keeping the original error as its cause. Exception types that cannot be constructed
+/// from the message alone (e.g. `UnicodeDecodeError`) are replaced by `RuntimeError`.
+pub fn error_context(message: TokenStream) -> TokenStream {
+    quote::quote! {
+        |err: ::pyo3::PyErr| {
+            let message = #message;
+            let context = match err.get_type(py).call1((::std::convert::AsRef::<str>::as_ref(&message),)) {
+                Ok(value) if value.is_instance_of::<::pyo3::exceptions::PyBaseException>() => {
+                    ::pyo3::PyErr::from_value(value)
+                }
+                _ => ::pyo3::exceptions::PyRuntimeError::new_err(message),
+            };
+            context.set_cause(py, Some(err));
+            context
+        }
+    }
 }
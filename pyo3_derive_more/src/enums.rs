@@ -0,0 +1,262 @@
+use std::collections::HashMap;
+
+use proc_macro2::TokenStream;
+use syn::{ext::IdentExt, spanned::Spanned, Data, DataEnum, DeriveInput, Fields, Ident, LitStr};
+
+use crate::{
+    attr::{ContainerAttrs, VariantAttrs},
+    dict::{error_context, variant_name},
+    error::{unsupported_shape, Errors},
+    import::ImportPath,
+};
+
+pub fn impl_py_enum(input: DeriveInput) -> syn::Result<TokenStream> {
+    // The name of the enum for which `PyEnum` is being derived
+    let enum_ident = &input.ident;
+
+    // The variants together with the names of their members, and the existing Python enum class
+    let (variants, container_attrs) = UnitVariant::parse(&input, "PyEnum")?;
+    let class = container_attrs
+        .class
+        .map(|class| ImportPath::parse(&class))
+        .transpose()?;
+    let idents: Vec<_> = variants.iter().map(|variant| variant.ident).collect();
+    let names: Vec<_> = variants.iter().map(|variant| &variant.name).collect();
+    let patterns: Vec<_> = variants.iter().map(UnitVariant::pattern).collect();
+
+    // The Python enum type, which is either imported or created once and then reused
+    let (enum_name, py_enum) = match &class {
+        Some(class) => {
+            let lookup = class.lookup();
+            let enum_name = class.path().to_owned();
+            (
+                enum_name,
+                quote::quote! {
+                    let class = #lookup;
+                    class.downcast::<::pyo3::types::PyType>().map_err(::std::convert::Into::into)
+                },
+            )
+        }
+        None => {
+            let enum_name = enum_ident.unraw().to_string();
+            (
+                enum_name.clone(),
+                quote::quote! {
+                    static ENUM: ::pyo3::sync::GILOnceCell<::pyo3::Py<::pyo3::types::PyType>> = ::pyo3::sync::GILOnceCell::new();
+                    ENUM.get_or_try_init(py, || {
+                        let members = ::pyo3::types::PyList::empty(py);
+                        #(
+                            members.append((#names, Self::#idents as isize))?;
+                        )*
+                        ::pyo3::types::PyModule::import(py, "enum")?
+                            .getattr("Enum")?
+                            .call1((#enum_name, members))?
+                            .extract::<::pyo3::Py<::pyo3::types::PyType>>()
+                    })
+                    .map(|py_enum| py_enum.as_ref(py))
+                },
+            )
+        }
+    };
+
+    // The generated implementation (without generics, which an enum without fields cannot use)
+    let vis = &input.vis;
+    let message = format!("Failed to convert '{enum_ident}' into a member of enum '{enum_name}'.");
+    let type_message = format!("Expected a member of enum '{enum_name}', got '{{}}'.");
+    let unknown_message = format!(
+        "Unknown member '{{}}' of enum '{enum_name}', expected one of {}.",
+        expected(&variants)
+    );
+    Ok(quote::quote! {
+        #[automatically_derived]
+        impl #enum_ident {
+            /// The Python enum type whose members correspond to the variants of this enum.
+            #vis fn py_enum(py: ::pyo3::Python) -> ::pyo3::PyResult<&::pyo3::types::PyType> {
+                #py_enum
+            }
+
+            /// Convert into the member of the Python enum type that corresponds to the variant.
+            #vis fn to_py_enum<'__py>(&self, py: ::pyo3::Python<'__py>) -> ::pyo3::PyResult<&'__py ::pyo3::PyAny> {
+                let name = match self {
+                    #(Self::#idents => ::pyo3::intern!(py, #names),)*
+                };
+                Self::py_enum(py)?.getattr(name)
+            }
+        }
+
+        #[automatically_derived]
+        impl ::pyo3::ToPyObject for #enum_ident {
+            fn to_object(&self, py: ::pyo3::Python) -> ::pyo3::PyObject {
+                self.to_py_enum(py).expect(#message).into()
+            }
+        }
+
+        #[automatically_derived]
+        impl ::pyo3::IntoPy<::pyo3::PyObject> for #enum_ident {
+            fn into_py(self, py: ::pyo3::Python) -> ::pyo3::PyObject {
+                ::pyo3::ToPyObject::to_object(&self, py)
+            }
+        }
+
+        #[automatically_derived]
+        impl<'__py> ::pyo3::FromPyObject<'__py> for #enum_ident {
+            fn extract(ob: &'__py ::pyo3::PyAny) -> ::pyo3::PyResult<Self> {
+                let py = ob.py();
+                if !ob.is_instance(Self::py_enum(py)?)? {
+                    return Err(::pyo3::exceptions::PyTypeError::new_err(format!(#type_message, ob.get_type().name()?)));
+                }
+                let name = ob.getattr(::pyo3::intern!(py, "name"))?.extract::<&str>()?;
+                match name {
+                    #(#patterns => Ok(Self::#idents),)*
+                    name => Err(::pyo3::exceptions::PyValueError::new_err(format!(#unknown_message, name))),
+                }
+            }
+        }
+    })
+}
+
+pub fn impl_py_str_enum(input: DeriveInput) -> syn::Result<TokenStream> {
+    // The name of the enum for which `PyStrEnum` is being derived
+    let enum_ident = &input.ident;
+
+    // The variants together with their string values
+    let (variants, _) = UnitVariant::parse(&input, "PyStrEnum")?;
+    let idents: Vec<_> = variants.iter().map(|variant| variant.ident).collect();
+    let names: Vec<_> = variants.iter().map(|variant| &variant.name).collect();
+    let patterns = variants.iter().map(UnitVariant::pattern);
+
+    // The generated implementation (without generics, which an enum without fields cannot use)
+    let vis = &input.vis;
+    let expected = expected(&variants);
+    let type_message =
+        format!("Expected a str with one of the values {expected} of enum '{enum_ident}'.");
+    let type_context = error_context(quote::quote! { #type_message });
+    let unknown_message =
+        format!("Invalid value '{{}}' of enum '{enum_ident}', expected one of {expected}.");
+    Ok(quote::quote! {
+        #[automatically_derived]
+        impl #enum_ident {
+            /// The Python string value that corresponds to the variant.
+            #vis fn as_py_str(&self) -> &'static str {
+                match self {
+                    #(Self::#idents => #names,)*
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl ::pyo3::ToPyObject for #enum_ident {
+            fn to_object(&self, py: ::pyo3::Python) -> ::pyo3::PyObject {
+                let value = match self {
+                    #(Self::#idents => ::pyo3::intern!(py, #names),)*
+                };
+                value.into()
+            }
+        }
+
+        #[automatically_derived]
+        impl ::pyo3::IntoPy<::pyo3::PyObject> for #enum_ident {
+            fn into_py(self, py: ::pyo3::Python) -> ::pyo3::PyObject {
+                ::pyo3::ToPyObject::to_object(&self, py)
+            }
+        }
+
+        #[automatically_derived]
+        impl<'__py> ::pyo3::FromPyObject<'__py> for #enum_ident {
+            fn extract(ob: &'__py ::pyo3::PyAny) -> ::pyo3::PyResult<Self> {
+                let py = ob.py();
+                match ob.extract::<&str>().map_err(#type_context)? {
+                    #(#patterns => Ok(Self::#idents),)*
+                    value => Err(::pyo3::exceptions::PyValueError::new_err(format!(#unknown_message, value))),
+                }
+            }
+        }
+    })
+}
+
+/// A variant of a fieldless enum together with its name
+struct UnitVariant<'a> {
+    ident: &'a Ident,
+    name: LitStr,
+    /// Additional names that are accepted for the variant on extraction
+    aliases: Vec<LitStr>,
+}
+
+impl<'a> UnitVariant<'a> {
+    /// Parse the variants of a fieldless enum for which `trait_name` is being derived, together
+    /// with the attributes of the enum
+    fn parse(input: &'a DeriveInput, trait_name: &str) -> syn::Result<(Vec<Self>, ContainerAttrs)> {
+        let enum_ident = &input.ident;
+        let mut errors = Errors::default();
+        let container_attrs = ContainerAttrs::from_input(input, &mut errors);
+        let variants = match &input.data {
+            Data::Enum(DataEnum { variants, .. }) => variants,
+            Data::Struct(data) => {
+                errors.push(unsupported_shape(
+                    data.struct_token.span,
+                    trait_name,
+                    enum_ident,
+                    "an enum without fields",
+                ));
+                return errors.finish((Vec::new(), container_attrs));
+            }
+            Data::Union(data) => {
+                errors.push(unsupported_shape(
+                    data.union_token.span,
+                    trait_name,
+                    enum_ident,
+                    "an enum without fields",
+                ));
+                return errors.finish((Vec::new(), container_attrs));
+            }
+        };
+
+        let mut unit_variants = Vec::with_capacity(variants.len());
+        let mut names = HashMap::with_capacity(variants.len());
+        for variant in variants {
+            let variant_attrs = VariantAttrs::from_attrs(&variant.attrs, &mut errors);
+            let variant_ident = &variant.ident;
+            if !matches!(variant.fields, Fields::Unit) {
+                errors.push(syn::Error::new(
+                    variant.fields.span(),
+                    format!("`{trait_name}` can be derived only for enums without fields"),
+                ));
+            }
+            let name = variant_name(variant_ident, &variant_attrs, container_attrs.rename_all);
+            let all_names = std::iter::once(("name", &name))
+                .chain(variant_attrs.aliases.iter().map(|alias| ("alias", alias)));
+            for (kind, name) in all_names {
+                if let Some(other_variant_ident) = names.insert(name.value(), variant_ident) {
+                    errors.push(syn::Error::new(
+                        name.span(),
+                        format!(
+                            "{kind} \"{}\" of variant `{variant_ident}` is already used by variant `{other_variant_ident}`",
+                            name.value()
+                        ),
+                    ));
+                }
+            }
+            unit_variants.push(Self {
+                ident: variant_ident,
+                name,
+                aliases: variant_attrs.aliases,
+            });
+        }
+        errors.finish((unit_variants, container_attrs))
+    }
+
+    /// Pattern that matches the name and all aliases of the variant
+    fn pattern(&self) -> TokenStream {
+        let Self { name, aliases, .. } = self;
+        quote::quote! { #name #(| #aliases)* }
+    }
+}
+
+/// The names of all variants for error messages, e.g. `'a', 'b'`
+fn expected(variants: &[UnitVariant]) -> String {
+    variants
+        .iter()
+        .map(|variant| format!("'{}'", variant.name.value()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,49 @@
+use proc_macro2::Span;
+use syn::Ident;
+
+/// Accumulator of errors that are reported together, so that all problems of the input are
+/// shown in a single compilation instead of one at a time
+#[derive(Default)]
+pub struct Errors(Option<syn::Error>);
+
+impl Errors {
+    pub fn push(&mut self, err: syn::Error) {
+        match &mut self.0 {
+            Some(errors) => errors.combine(err),
+            None => self.0 = Some(err),
+        }
+    }
+
+    /// Take the value of `result`, or store its error and return `None`
+    pub fn take<T>(&mut self, result: syn::Result<T>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(err) => {
+                self.push(err);
+                None
+            }
+        }
+    }
+
+    /// Return all accumulated errors, or `value` if there are none
+    pub fn finish<T>(self, value: T) -> syn::Result<T> {
+        match self.0 {
+            Some(errors) => Err(errors),
+            None => Ok(value),
+        }
+    }
+}
+
+/// Error for an input whose data is not of the shape that `trait_name` can be derived for, e.g.
+/// `expected` is "a struct with named fields"
+pub fn unsupported_shape(
+    span: Span,
+    trait_name: &str,
+    ident: &Ident,
+    expected: &str,
+) -> syn::Error {
+    syn::Error::new(
+        span,
+        format!("`{trait_name}` cannot be derived for `{ident}` because it is not {expected}"),
+    )
+}
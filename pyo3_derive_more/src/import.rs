@@ -0,0 +1,136 @@
+use proc_macro2::TokenStream;
+use syn::LitStr;
+
+/// Path to a Python object that is imported from a module, i.e. `module.attr` or
+/// `[package.module].attr` where the module enclosed in `[` and `]` is imported by its full path
+/// (deep import) as with the `bind_python!` macros
+pub struct ImportPath {
+    module: String,
+    attrs: Vec<String>,
+    /// The original path used in error messages
+    path: String,
+}
+
+impl ImportPath {
+    pub fn parse(lit: &LitStr) -> syn::Result<Self> {
+        let path = lit.value();
+        let error = |reason: &str| {
+            syn::Error::new(
+                lit.span(),
+                format!("invalid Python path `{path}`, {reason}"),
+            )
+        };
+        let (module, attrs) = match path.strip_prefix('[') {
+            Some(deep_path) => {
+                let (module, attrs) = deep_path
+                    .split_once(']')
+                    .ok_or_else(|| error("expected `]` after the module of a deep import"))?;
+                let attrs = attrs.strip_prefix('.').ok_or_else(|| {
+                    error("expected `.attr` after the module of a deep import, e.g. `[package.module].attr`")
+                })?;
+                (module, attrs)
+            }
+            None => path.split_once('.').ok_or_else(|| {
+                error("expected an attribute of a module, e.g. `module.attr` or `[package.module].attr`")
+            })?,
+        };
+        if !module.split('.').chain(attrs.split('.')).all(is_identifier) {
+            return Err(error(
+                "expected identifiers separated by `.`, e.g. `module.attr` or `[package.module].attr`",
+            ));
+        }
+        Ok(Self {
+            module: module.to_owned(),
+            attrs: attrs.split('.').map(str::to_owned).collect(),
+            path,
+        })
+    }
+
+    /// The original path, e.g. `[package.module].attr`
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Expression that imports the module and resolves the attributes, which requires `py` in scope
+    /// and propagates errors with `?`
+    pub fn lookup(&self) -> TokenStream {
+        let Self { module, attrs, .. } = self;
+        quote::quote! {
+            ::pyo3::types::PyModule::import(py, ::pyo3::intern!(py, #module))?
+                #(.getattr(::pyo3::intern!(py, #attrs))?)*
+        }
+    }
+}
+
+/// Whether the name is a valid Python identifier (restricted to ASCII)
+pub fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .map_or(false, |first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let import_path = parse_path("torch.optim.Adam").unwrap();
+        assert_eq!(import_path.module, "torch");
+        assert_eq!(import_path.attrs, ["optim", "Adam"]);
+        assert_eq!(import_path.path(), "torch.optim.Adam");
+
+        let import_path = parse_path("[omni.isaac.kit].SimulationApp").unwrap();
+        assert_eq!(import_path.module, "omni.isaac.kit");
+        assert_eq!(import_path.attrs, ["SimulationApp"]);
+        assert_eq!(import_path.path(), "[omni.isaac.kit].SimulationApp");
+    }
+
+    #[test]
+    fn parse_invalid() {
+        let expected = [
+            ("torch", "expected an attribute of a module"),
+            (
+                "[omni.isaac.kit",
+                "expected `]` after the module of a deep import",
+            ),
+            (
+                "[omni.isaac.kit]",
+                "expected `.attr` after the module of a deep import",
+            ),
+            (
+                "[omni.isaac.kit]SimulationApp",
+                "expected `.attr` after the module of a deep import",
+            ),
+            ("torch..Adam", "expected identifiers separated by `.`"),
+            ("torch.optim.", "expected identifiers separated by `.`"),
+            ("[].Adam", "expected identifiers separated by `.`"),
+            ("torch.2d", "expected identifiers separated by `.`"),
+            ("torch.optim-Adam", "expected identifiers separated by `.`"),
+        ];
+        for (path, reason) in expected {
+            let err = parse_path(path).err().unwrap().to_string();
+            assert!(
+                err.starts_with(&format!("invalid Python path `{path}`, {reason}")),
+                "{err}"
+            );
+        }
+    }
+
+    #[test]
+    fn identifier() {
+        assert!(is_identifier("Adam"));
+        assert!(is_identifier("_private"));
+        assert!(is_identifier("conv2d"));
+        assert!(!is_identifier(""));
+        assert!(!is_identifier("2d"));
+        assert!(!is_identifier("kebab-case"));
+        assert!(!is_identifier("café"));
+    }
+
+    fn parse_path(path: &str) -> syn::Result<ImportPath> {
+        ImportPath::parse(&LitStr::new(path, proc_macro2::Span::call_site()))
+    }
+}
@@ -0,0 +1,109 @@
+use proc_macro2::TokenStream;
+use syn::{Data, DeriveInput};
+
+use crate::{
+    bound::with_bound,
+    dict::{error_context, insert_items, Conversion, DictShape},
+    error::{unsupported_shape, Errors},
+    import::ImportPath,
+};
+
+pub fn impl_into_py_instance(input: DeriveInput) -> syn::Result<TokenStream> {
+    // The name of the struct for which `IntoPyInstance` is being derived
+    let struct_ident = &input.ident;
+
+    // The fields together with their attributes and keys (which become the keyword arguments)
+    let mut errors = Errors::default();
+    let parsed = errors.take(DictShape::parse(&input, "IntoPyInstance"));
+    if let Data::Enum(data) = &input.data {
+        errors.push(unsupported_shape(
+            data.enum_token.span,
+            "IntoPyInstance",
+            struct_ident,
+            "a struct with named fields",
+        ));
+    }
+    let (shape, extras, container_attrs) = match parsed {
+        Some(parsed) => parsed,
+        None => return errors.finish(TokenStream::new()),
+    };
+
+    // The Python class that is constructed
+    let class = match &container_attrs.class {
+        Some(class) => errors.take(ImportPath::parse(class)),
+        None => {
+            errors.push(syn::Error::new(
+                struct_ident.span(),
+                format!("struct `{struct_ident}` requires the `#[pyo3_more(class = \"module.Class\")]` attribute with the Python class to construct"),
+            ));
+            None
+        }
+    };
+    let class = match class {
+        Some(class) => {
+            errors.finish(())?;
+            class
+        }
+        None => return errors.finish(TokenStream::new()),
+    };
+
+    // The generics with `IntoPy<PyObject>` bounds for the type parameters of converted fields, and
+    // `TryIntoPyDict` bounds for the type parameters of flattened and nested fields
+    let generics = with_bound(
+        &input.generics,
+        shape.field_types(),
+        &syn::parse_quote!(::pyo3::IntoPy<::pyo3::PyObject>),
+    );
+    let generics = with_bound(
+        &generics,
+        shape.merged_types(),
+        &syn::parse_quote!(::pyo3_macros_more::TryIntoPyDict),
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    // The insertion of all fields into the keyword arguments of the constructor
+    let body = insert_items(&shape, &extras, Conversion::Owned);
+
+    // The generated implementation
+    let vis = &input.vis;
+    let lookup = class.lookup();
+    let doc = format!(
+        " Construct an instance of the Python class `{}` with the fields as keyword arguments, returning an error if any of the fields fails to convert.",
+        class.path()
+    );
+    let message = format!(
+        "Failed to construct an instance of '{}' from '{struct_ident}'.",
+        class.path()
+    );
+    let context = error_context(quote::quote! { #message });
+    Ok(quote::quote! {
+        #[automatically_derived]
+        impl #impl_generics #struct_ident #ty_generics #where_clause {
+            #[doc = #doc]
+            #vis fn try_into_py_instance(self, py: ::pyo3::Python) -> ::pyo3::PyResult<&::pyo3::PyAny> {
+                let kwargs = ::pyo3::types::PyDict::new(py);
+                #body
+                let class = #lookup;
+                class.call((), Some(kwargs)).map_err(#context)
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::pyo3::IntoPy<::pyo3::PyObject> for #struct_ident #ty_generics #where_clause {
+            fn into_py(self, py: ::pyo3::Python) -> ::pyo3::PyObject {
+                self.try_into_py_instance(py).expect(#message).into()
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::std::convert::TryFrom<#struct_ident #ty_generics> for ::pyo3::Py<::pyo3::PyAny> #where_clause {
+            type Error = ::pyo3::PyErr;
+
+            fn try_from(value: #struct_ident #ty_generics) -> ::pyo3::PyResult<Self> {
+                ::pyo3::Python::with_gil(|py| {
+                    value.try_into_py_instance(py).map(::std::convert::Into::into)
+                })
+            }
+        }
+    })
+}
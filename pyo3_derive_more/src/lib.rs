@@ -1,11 +1,179 @@
 //! More procedural macros for `PyO3`.
+//!
+//! The code generated by [`IntoPyDict`](derive@IntoPyDict), [`ToPyDict`](derive@ToPyDict) and
+//! [`IntoPyInstance`](derive@IntoPyInstance) refers to `pyo3_macros_more`, which must also be a
+//! dependency.
+//!
+//! # Attributes
+//!
+//! All derives are configured with `#[pyo3_more(...)]` attributes that share one set of keys, so a
+//! type can derive several of them at once. Unknown keys and keys that do not apply to the shape of
+//! the type are compile errors, while each derive ignores the keys that only other derives use.
+//!
+//! Attributes of a struct or enum:
+//! - `rename_all = "rule"`: Convert the names of all fields (or variants of an enum) according to
+//!   `rule`, which is one of `"lowercase"`, `"UPPERCASE"`, `"PascalCase"`, `"camelCase"`,
+//!   `"snake_case"`, `"SCREAMING_SNAKE_CASE"`, `"kebab-case"` or `"SCREAMING-KEBAB-CASE"`
+//! - `strip_trailing_underscore`: Strip the trailing underscore from the names of fields that are
+//!   Python keywords without it, e.g. `lambda_` becomes `"lambda"`
+//! - `extra(key = "key", with = "path")`: Add a dictionary item computed by `path(&self)`, e.g.
+//!   `with = "Self::total_steps"`; it can be repeated
+//! - `tag = "key"` (enums): Store the name of the variant under `key` and its fields next to it,
+//!   e.g. `{"type": "adam", "lr": 0.001}`; the only field of a tuple variant is merged with the tag
+//! - `content = "key"` (enums, with `tag`): Store the fields of the variant under `key`, e.g.
+//!   `{"type": "adam", "data": {"lr": 0.001}}`
+//! - `class = "module.Class"`: The Python class of [`IntoPyInstance`](derive@IntoPyInstance) or
+//!   the existing Python enum of [`PyEnum`](derive@PyEnum), where the module of a deep import is
+//!   enclosed in `[` and `]` as with the `bind_python!` macros, e.g. `"[omni.isaac.kit].SimulationApp"`
+//! - `tuple` (structs): Convert the named fields into a tuple with [`IntoPyTuple`](derive@IntoPyTuple)
+//! - `diff` (structs): Also derive `apply_diff_to_py_object` with [`ApplyToPyObject`](derive@ApplyToPyObject)
+//!
+//! Attributes of a variant:
+//! - `rename = "name"`: Use `name` instead of the variant identifier
+//! - `rename_all = "rule"`: Convert the names of all fields of the variant according to `rule`
+//! - `alias = "name"`: Also accept `name` on extraction with [`PyEnum`](derive@PyEnum) or
+//!   [`PyStrEnum`](derive@PyStrEnum); it can be repeated
+//!
+//! Attributes of a field:
+//! - `rename = "key"`: Use `key` instead of the field name; the `r#` prefix of raw identifiers is
+//!   always stripped
+//! - `skip`: Omit the field, which is initialized with `Default::default()` on extraction
+//! - `skip_if = "path"`: Omit the field if `path(&field)` returns `true`, e.g. `"Option::is_none"`,
+//!   and initialize it with `Default::default()` if it is missing on extraction
+//! - `with = "module"`: Convert the field with `module::to_py(&T, Python) -> PyResult<PyObject>`
+//!   and extract it with `module::from_py(&PyAny) -> PyResult<T>`
+//! - `flatten`: Merge the items of the field, which must implement `pyo3_macros_more::TryIntoPyDict`
+//!   (or `pyo3_macros_more::TryToPyDict`), into the dictionary; colliding keys raise `KeyError`
+//! - `nested`: Convert the field, whose type implements `pyo3_macros_more::TryIntoPyDict` (or
+//!   `pyo3_macros_more::TryToPyDict`), into a nested dictionary; the type can be wrapped in
+//!   `Option<T>`, `Box<T>`, `Vec<T>`, `VecDeque<T>`, `LinkedList<T>`, `[T; N]`, `HashMap<K, T>`
+//!   or `BTreeMap<K, T>`
+//! - `path = "attr.attr"`: Read or set the field in a nested attribute of an object, e.g. `"state.pose.x"`
+//! - `default`: Initialize the field with `Default::default()` if its attribute is missing
 use proc_macro::TokenStream;
 
+mod attr;
+mod bound;
+mod case;
+mod dataclass;
 mod dict;
+mod enums;
+mod error;
+mod import;
+mod instance;
+mod nested;
+mod object;
+mod tuple;
 
-/// Derive `pyo3::types::IntoPyDict` and `From<T> for pyo3::Py<pyo3::types::PyDict>`
-/// implementations for a struct with named fields.
-#[proc_macro_derive(IntoPyDict)]
+/// Derive `pyo3::types::IntoPyDict`, `pyo3_macros_more::TryIntoPyDict` and
+/// `TryFrom<T> for pyo3::Py<pyo3::types::PyDict>` implementations for a struct with named fields or
+/// a tagged enum, configured with the crate [attributes](crate#attributes).
+///
+/// The generated `try_into_py_dict(self, py) -> PyResult<&PyDict>` method returns errors with the
+/// failing field as context, while `pyo3::types::IntoPyDict::into_py_dict` panics on them.
+#[proc_macro_derive(IntoPyDict, attributes(pyo3_more))]
 pub fn derive_into_pydict(input: TokenStream) -> TokenStream {
     dict::impl_into_pydict(syn::parse_macro_input!(input))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive `pyo3::ToPyObject`, `pyo3_macros_more::TryToPyDict` and `TryFrom<&T> for pyo3::Py<pyo3::types::PyDict>`
+/// implementations, i.e. the non-consuming counterpart of [`IntoPyDict`](derive@IntoPyDict).
+///
+/// The generated `try_to_py_dict(&self, py)` method returns errors, while `to_py_dict(&self, py)`
+/// and `pyo3::ToPyObject::to_object` panic on them.
+#[proc_macro_derive(ToPyDict, attributes(pyo3_more))]
+pub fn derive_to_pydict(input: TokenStream) -> TokenStream {
+    dict::impl_to_pydict(syn::parse_macro_input!(input))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive `TryFrom<&pyo3::types::PyDict>`, `TryFrom<pyo3::Py<pyo3::types::PyDict>>` and
+/// `pyo3::FromPyObject` implementations for a struct with named fields or a tagged enum, configured
+/// with the crate [attributes](crate#attributes). Fields of type `Option<T>` may be missing.
+#[proc_macro_derive(FromPyDict, attributes(pyo3_more))]
+pub fn derive_from_pydict(input: TokenStream) -> TokenStream {
+    dict::impl_from_pydict(syn::parse_macro_input!(input))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive a Python dataclass type that mirrors a struct with named fields, returned by a generated
+/// `py_dataclass(py)` function, together with conversions of the struct into its instances.
+///
+/// The fields are annotated with the Python types of their Rust types, e.g. `typing.List[float]`,
+/// and named according to the crate [attributes](crate#attributes). Type parameters are not supported.
+#[proc_macro_derive(PyDataclass, attributes(pyo3_more))]
+pub fn derive_py_dataclass(input: TokenStream) -> TokenStream {
+    dataclass::impl_py_dataclass(syn::parse_macro_input!(input))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive `pyo3::IntoPy<pyo3::PyObject>` and `TryFrom<T> for pyo3::Py<pyo3::PyAny>` implementations
+/// that call the Python class of the `class` [attribute](crate#attributes) with the fields of a
+/// struct as keyword arguments.
+#[proc_macro_derive(IntoPyInstance, attributes(pyo3_more))]
+pub fn derive_into_py_instance(input: TokenStream) -> TokenStream {
+    instance::impl_into_py_instance(syn::parse_macro_input!(input))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive `TryFrom<&pyo3::PyAny>` and `TryFrom<&pyo3::Py<pyo3::PyAny>>` implementations that fill
+/// a struct with named fields from the attributes of an arbitrary Python object.
+///
+/// The generated `from_py_attrs(ob)` function reads all fields while holding the GIL once, from
+/// the attributes named by the crate [attributes](crate#attributes), including `path` and `default`.
+#[proc_macro_derive(FromPyAttrs, attributes(pyo3_more))]
+pub fn derive_from_py_attrs(input: TokenStream) -> TokenStream {
+    object::impl_from_py_attrs(syn::parse_macro_input!(input))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive an `apply_to_py_object(&self, ob)` method that sets the fields of a struct as attributes
+/// of an arbitrary Python object, i.e. the counterpart of [`FromPyAttrs`](derive@FromPyAttrs).
+///
+/// With the `diff` [attribute](crate#attributes), `apply_diff_to_py_object(&self, previous, ob)`
+/// sets only the fields that differ from `previous`.
+#[proc_macro_derive(ApplyToPyObject, attributes(pyo3_more))]
+pub fn derive_apply_to_py_object(input: TokenStream) -> TokenStream {
+    object::impl_apply_to_py_object(syn::parse_macro_input!(input))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive `pyo3::ToPyObject`, `pyo3::IntoPy<pyo3::PyObject>` and `pyo3::FromPyObject` implementations
+/// that map the variants of an enum without fields to the members of a Python `enum.Enum`.
+///
+/// The enum type is created once with the discriminants as values, or imported from the `class`
+/// [attribute](crate#attributes), and returned by a generated `py_enum(py)` function.
+#[proc_macro_derive(PyEnum, attributes(pyo3_more))]
+pub fn derive_py_enum(input: TokenStream) -> TokenStream {
+    enums::impl_py_enum(syn::parse_macro_input!(input))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive `pyo3::ToPyObject`, `pyo3::IntoPy<pyo3::PyObject>` and `pyo3::FromPyObject` implementations
+/// that map the variants of an enum without fields to Python `str` values, e.g. for parameters
+/// annotated with `typing.Literal["nearest", "bilinear"]`, named by the crate [attributes](crate#attributes).
+#[proc_macro_derive(PyStrEnum, attributes(pyo3_more))]
+pub fn derive_py_str_enum(input: TokenStream) -> TokenStream {
+    enums::impl_py_str_enum(syn::parse_macro_input!(input))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive `pyo3::IntoPy<pyo3::Py<pyo3::types::PyTuple>>` and `From<T> for pyo3::Py<pyo3::types::PyTuple>`
+/// implementations for a tuple struct, or for a struct with named fields with the `tuple`
+/// [attribute](crate#attributes).
+#[proc_macro_derive(IntoPyTuple, attributes(pyo3_more))]
+pub fn derive_into_pytuple(input: TokenStream) -> TokenStream {
+    tuple::impl_into_pytuple(syn::parse_macro_input!(input))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
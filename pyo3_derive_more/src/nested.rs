@@ -0,0 +1,130 @@
+use proc_macro2::TokenStream;
+use syn::{GenericArgument, PathArguments, Type};
+
+use crate::dict::Conversion;
+
+/// The type of a field with the `nested` attribute, i.e. a type that is converted into a
+/// dictionary, possibly wrapped in an option, a box or a collection
+pub enum NestedType<'a> {
+    /// A type that implements `pyo3_macros_more::TryIntoPyDict` (or `pyo3_macros_more::TryToPyDict`)
+    Dict(&'a Type),
+    /// `Option<T>`, converted into `None` or the converted value
+    Option(Box<NestedType<'a>>),
+    /// `Box<T>`, converted as the boxed value
+    Box(Box<NestedType<'a>>),
+    /// `Vec<T>`, `VecDeque<T>`, `LinkedList<T>` or `[T; N]`, converted into a list
+    List(Box<NestedType<'a>>),
+    /// `HashMap<K, T>` or `BTreeMap<K, T>`, converted into a dictionary with the keys converted by
+    /// `pyo3::IntoPy<pyo3::PyObject>` or `pyo3::ToPyObject`
+    Map(&'a Type, Box<NestedType<'a>>),
+}
+
+impl<'a> NestedType<'a> {
+    pub fn parse(ty: &'a Type) -> Self {
+        match ty {
+            Type::Paren(paren) => Self::parse(&paren.elem),
+            Type::Group(group) => Self::parse(&group.elem),
+            Type::Array(array) => Self::List(Box::new(Self::parse(&array.elem))),
+            Type::Path(type_path) if type_path.qself.is_none() => {
+                let segment = type_path.path.segments.last().unwrap();
+                let args: Vec<_> = match &segment.arguments {
+                    PathArguments::AngleBracketed(arguments) => arguments
+                        .args
+                        .iter()
+                        .filter_map(|arg| match arg {
+                            GenericArgument::Type(ty) => Some(ty),
+                            _ => None,
+                        })
+                        .collect(),
+                    _ => Vec::new(),
+                };
+                match (segment.ident.to_string().as_str(), args.as_slice()) {
+                    ("Option", [inner]) => Self::Option(Box::new(Self::parse(inner))),
+                    ("Box", [inner]) => Self::Box(Box::new(Self::parse(inner))),
+                    ("Vec" | "VecDeque" | "LinkedList", [elem]) => {
+                        Self::List(Box::new(Self::parse(elem)))
+                    }
+                    ("HashMap" | "BTreeMap", [key, value, ..]) => {
+                        Self::Map(key, Box::new(Self::parse(value)))
+                    }
+                    _ => Self::Dict(ty),
+                }
+            }
+            _ => Self::Dict(ty),
+        }
+    }
+
+    /// The types that are converted into dictionaries
+    pub fn dict_types(&self) -> Vec<&'a Type> {
+        match self {
+            Self::Dict(ty) => vec![*ty],
+            Self::Option(inner) | Self::Box(inner) | Self::List(inner) | Self::Map(_, inner) => {
+                inner.dict_types()
+            }
+        }
+    }
+
+    /// The types of the keys of all maps
+    pub fn key_types(&self) -> Vec<&'a Type> {
+        match self {
+            Self::Dict(_) => Vec::new(),
+            Self::Option(inner) | Self::Box(inner) | Self::List(inner) => inner.key_types(),
+            Self::Map(key, inner) => std::iter::once(*key).chain(inner.key_types()).collect(),
+        }
+    }
+
+    /// The conversion of a `value` into a `pyo3::PyObject`, which requires `py` in scope and
+    /// propagates the errors of dictionaries and collections with `?` after wrapping them with `context`
+    pub fn conversion(
+        &self,
+        value: &TokenStream,
+        conversion: Conversion,
+        context: &TokenStream,
+    ) -> TokenStream {
+        match self {
+            Self::Dict(_) => {
+                let items = conversion.items(value, context);
+                quote::quote! {
+                    ::pyo3::IntoPy::<::pyo3::PyObject>::into_py(#items, py)
+                }
+            }
+            Self::Option(inner) => {
+                let inner = inner.conversion(&quote::quote! { value }, conversion, context);
+                quote::quote! {
+                    match #value {
+                        ::std::option::Option::Some(value) => #inner,
+                        ::std::option::Option::None => py.None(),
+                    }
+                }
+            }
+            Self::Box(inner) => {
+                let value = match conversion {
+                    Conversion::Owned => quote::quote! { *#value },
+                    Conversion::Borrowed => quote::quote! { &**#value },
+                };
+                inner.conversion(&value, conversion, context)
+            }
+            Self::List(inner) => {
+                let inner = inner.conversion(&quote::quote! { value }, conversion, context);
+                quote::quote! {{
+                    let list = ::pyo3::types::PyList::empty(py);
+                    for value in #value {
+                        list.append(#inner).map_err(#context)?;
+                    }
+                    ::pyo3::IntoPy::<::pyo3::PyObject>::into_py(list, py)
+                }}
+            }
+            Self::Map(_, inner) => {
+                let inner = inner.conversion(&quote::quote! { value }, conversion, context);
+                let key = conversion.value(&quote::quote! { key });
+                quote::quote! {{
+                    let dict = ::pyo3::types::PyDict::new(py);
+                    for (key, value) in #value {
+                        dict.set_item(#key, #inner).map_err(#context)?;
+                    }
+                    ::pyo3::IntoPy::<::pyo3::PyObject>::into_py(dict, py)
+                }}
+            }
+        }
+    }
+}
@@ -0,0 +1,341 @@
+use proc_macro2::TokenStream;
+use syn::{spanned::Spanned, Data, DataStruct, DeriveInput, Fields, LitStr};
+
+use crate::{
+    attr::ContainerAttrs,
+    bound::{with_bound, with_py_lifetime},
+    dict::{converted_types, dict_fields, error_context, Conversion, DictField},
+    error::{unsupported_shape, Errors},
+    import::is_identifier,
+};
+
+pub fn impl_from_py_attrs(input: DeriveInput) -> syn::Result<TokenStream> {
+    // The name of the struct for which `FromPyAttrs` is being derived
+    let struct_ident = &input.ident;
+
+    // The fields together with the paths of their attributes
+    let (fields, _) = ObjectField::parse(&input, "FromPyAttrs")?;
+
+    // The generics extended by the lifetime of the object, with `FromPyObject` bounds for the
+    // type parameters of extracted fields
+    let generics = with_bound(
+        &input.generics,
+        converted_types(fields.iter().map(|field| &field.field)),
+        &syn::parse_quote!(::pyo3::FromPyObject<'__py>),
+    );
+    let generics = with_py_lifetime(&generics, &input.generics);
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    // The extraction of all fields from the attributes of the object
+    let field_idents = fields.iter().map(|field| field.field.ident);
+    let extract_fields = fields.iter().map(|object_field| {
+        let ObjectField { field, path } = object_field;
+        let DictField {
+            ident: field_ident,
+            ty: field_ty,
+            owner,
+            ..
+        } = field;
+        if field.attrs.skip {
+            return quote::quote! { ::std::default::Default::default() };
+        }
+        let message = format!(
+            "Failed to extract attribute '{}' required by field '{field_ident}' of {owner}.",
+            path.join(".")
+        );
+        let context = error_context(quote::quote! { #message });
+        let extract = match &field.attrs.with {
+            Some(with) => quote::quote! { #with::from_py(value) },
+            None => quote::quote! { value.extract::<#field_ty>() },
+        };
+        let missing = if field.attrs.default || field.attrs.skip_if.is_some() {
+            quote::quote! {
+                Err(err) if err.is_instance_of::<::pyo3::exceptions::PyAttributeError>(py) => ::std::default::Default::default(),
+            }
+        } else {
+            TokenStream::new()
+        };
+        quote::quote! {
+            match ::std::result::Result::<&::pyo3::PyAny, ::pyo3::PyErr>::Ok(ob)
+                #(.and_then(|ob| ob.getattr(::pyo3::intern!(py, #path))))*
+            {
+                Ok(value) => #extract.map_err(#context)?,
+                #missing
+                Err(err) => return Err((#context)(err)),
+            }
+        }
+    });
+
+    // The extraction from an owned object, which is possible only without lifetimes
+    let try_from_py = if input.generics.lifetimes().next().is_none() {
+        let generics = with_bound(
+            &input.generics,
+            converted_types(fields.iter().map(|field| &field.field)),
+            &syn::parse_quote!(for<'__py> ::pyo3::FromPyObject<'__py>),
+        );
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        Some(quote::quote! {
+            #[automatically_derived]
+            impl #impl_generics ::std::convert::TryFrom<&::pyo3::Py<::pyo3::PyAny>> for #struct_ident #ty_generics #where_clause {
+                type Error = ::pyo3::PyErr;
+
+                fn try_from(value: &::pyo3::Py<::pyo3::PyAny>) -> ::pyo3::PyResult<Self> {
+                    ::pyo3::Python::with_gil(|py| Self::from_py_attrs(value.as_ref(py)))
+                }
+            }
+        })
+    } else {
+        None
+    };
+
+    // The generated implementation
+    let vis = &input.vis;
+    Ok(quote::quote! {
+        #[automatically_derived]
+        impl #impl_generics #struct_ident #ty_generics #where_clause {
+            /// Extract all fields from the attributes of a Python object.
+            #vis fn from_py_attrs(ob: &'__py ::pyo3::PyAny) -> ::pyo3::PyResult<Self> {
+                let py = ob.py();
+                Ok(Self {
+                    #(
+                        #field_idents: #extract_fields,
+                    )*
+                })
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::std::convert::TryFrom<&'__py ::pyo3::PyAny> for #struct_ident #ty_generics #where_clause {
+            type Error = ::pyo3::PyErr;
+
+            fn try_from(ob: &'__py ::pyo3::PyAny) -> ::pyo3::PyResult<Self> {
+                Self::from_py_attrs(ob)
+            }
+        }
+
+        #try_from_py
+    })
+}
+
+pub fn impl_apply_to_py_object(input: DeriveInput) -> syn::Result<TokenStream> {
+    // The name of the struct for which `ApplyToPyObject` is being derived
+    let struct_ident = &input.ident;
+
+    // The fields together with the paths of their attributes
+    let (fields, container_attrs) = ObjectField::parse(&input, "ApplyToPyObject")?;
+
+    // The generics with `ToPyObject` bounds (and `PartialEq` bounds for the comparison with the
+    // previous value) for the type parameters of converted fields
+    let generics = with_bound(
+        &input.generics,
+        converted_types(fields.iter().map(|field| &field.field)),
+        &syn::parse_quote!(::pyo3::ToPyObject),
+    );
+    let generics = if container_attrs.diff {
+        with_bound(
+            &generics,
+            fields
+                .iter()
+                .filter(|field| !field.field.attrs.skip)
+                .map(|field| field.field.ty),
+            &syn::parse_quote!(::std::cmp::PartialEq),
+        )
+    } else {
+        generics
+    };
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    // The assignment of the fields that are not skipped to the attributes of the object, optionally
+    // only if the field differs from its previous value
+    let set_attrs = |diff: bool| {
+        fields
+            .iter()
+            .filter(|object_field| !object_field.field.attrs.skip)
+            .map(move |object_field| {
+                let ObjectField { field, path } = object_field;
+                let DictField {
+                    ident: field_ident,
+                    binding,
+                    owner,
+                    ..
+                } = field;
+                let message = format!(
+                    "Failed to set attribute '{}' from field '{field_ident}' of {owner}.",
+                    path.join(".")
+                );
+                let context = error_context(quote::quote! { #message });
+                let value = match &field.attrs.with {
+                    Some(with) => quote::quote! {
+                        #with::to_py(#binding, py).map_err(#context)?
+                    },
+                    None => Conversion::Borrowed.value(binding),
+                };
+                let (name, parents) = path.split_last().unwrap();
+                let set_attr = quote::quote! {
+                    ::std::result::Result::<&::pyo3::PyAny, ::pyo3::PyErr>::Ok(ob)
+                        #(.and_then(|ob| ob.getattr(::pyo3::intern!(py, #parents))))*
+                        .and_then(|ob| ob.setattr(::pyo3::intern!(py, #name), #value))
+                        .map_err(#context)?;
+                };
+                let mut conditions = Vec::new();
+                if let Some(skip_if) = &field.attrs.skip_if {
+                    conditions.push(quote::quote! { !#skip_if(#binding) });
+                }
+                if diff {
+                    conditions.push(quote::quote! {
+                        previous.map_or(true, |previous| previous.#field_ident != *#binding)
+                    });
+                }
+                if conditions.is_empty() {
+                    set_attr
+                } else {
+                    quote::quote! {
+                        if #(#conditions)&&* {
+                            #set_attr
+                        }
+                    }
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+    let (idents, bindings): (Vec<_>, Vec<_>) = fields
+        .iter()
+        .filter(|object_field| !object_field.field.attrs.skip)
+        .map(|object_field| (object_field.field.ident, &object_field.field.binding))
+        .unzip();
+    let pattern = quote::quote! {
+        { #(#idents: #bindings,)* .. }
+    };
+
+    // The assignment of only the fields that changed since the previous value
+    let vis = &input.vis;
+    let apply_diff = if container_attrs.diff {
+        let set_attrs = set_attrs(true);
+        Some(quote::quote! {
+            /// Set the fields that differ from `previous` (or all fields if it is `None`) as attributes of a Python object.
+            #vis fn apply_diff_to_py_object(&self, previous: ::std::option::Option<&Self>, ob: &::pyo3::PyAny) -> ::pyo3::PyResult<()> {
+                let py = ob.py();
+                let Self #pattern = self;
+                #(#set_attrs)*
+                Ok(())
+            }
+        })
+    } else {
+        None
+    };
+
+    // The generated implementation
+    let set_attrs = set_attrs(false);
+    Ok(quote::quote! {
+        #[automatically_derived]
+        impl #impl_generics #struct_ident #ty_generics #where_clause {
+            /// Set all fields as attributes of a Python object.
+            #vis fn apply_to_py_object(&self, ob: &::pyo3::PyAny) -> ::pyo3::PyResult<()> {
+                let py = ob.py();
+                let Self #pattern = self;
+                #(#set_attrs)*
+                Ok(())
+            }
+
+            #apply_diff
+        }
+    })
+}
+
+/// A named field together with the path of the attribute that holds it
+struct ObjectField<'a> {
+    field: DictField<'a>,
+    /// The names of the nested attributes, i.e. `["state", "pose", "x"]` for `state.pose.x`
+    path: Vec<String>,
+}
+
+impl<'a> ObjectField<'a> {
+    /// Parse the fields of a struct for which `trait_name` is being derived, together with the
+    /// attributes of the struct
+    fn parse(input: &'a DeriveInput, trait_name: &str) -> syn::Result<(Vec<Self>, ContainerAttrs)> {
+        let struct_ident = &input.ident;
+        let mut errors = Errors::default();
+        let container_attrs = ContainerAttrs::from_input(input, &mut errors);
+        let fields = match &input.data {
+            Data::Struct(DataStruct {
+                fields: Fields::Named(fields),
+                ..
+            }) => dict_fields(
+                &fields.named,
+                container_attrs.rename_all,
+                container_attrs.strip_trailing_underscore,
+                &format!("struct '{struct_ident}'"),
+                None,
+                &mut errors,
+            ),
+            Data::Struct(DataStruct { fields, .. }) => {
+                let span = match fields {
+                    Fields::Unit => struct_ident.span(),
+                    _ => fields.span(),
+                };
+                errors.push(unsupported_shape(
+                    span,
+                    trait_name,
+                    struct_ident,
+                    "a struct with named fields",
+                ));
+                Vec::new()
+            }
+            Data::Enum(data) => {
+                errors.push(unsupported_shape(
+                    data.enum_token.span,
+                    trait_name,
+                    struct_ident,
+                    "a struct with named fields",
+                ));
+                Vec::new()
+            }
+            Data::Union(data) => {
+                errors.push(unsupported_shape(
+                    data.union_token.span,
+                    trait_name,
+                    struct_ident,
+                    "a struct with named fields",
+                ));
+                Vec::new()
+            }
+        };
+
+        let mut object_fields = Vec::with_capacity(fields.len());
+        for field in fields {
+            if field.attrs.flatten {
+                errors.push(syn::Error::new(
+                    field.ident.span(),
+                    "`flatten` attribute is not supported for fields stored in attributes",
+                ));
+            }
+            if field.attrs.nested {
+                errors.push(syn::Error::new(
+                    field.ident.span(),
+                    "`nested` attribute is not supported for fields stored in attributes",
+                ));
+            }
+            let path = match &field.attrs.path {
+                Some(path) => errors.take(attr_path(path)).unwrap_or_default(),
+                None => vec![field.key.value()],
+            };
+            object_fields.push(Self { field, path });
+        }
+        errors.finish((object_fields, container_attrs))
+    }
+}
+
+/// The names of the nested attributes of a path such as `state.pose.x`
+fn attr_path(path: &LitStr) -> syn::Result<Vec<String>> {
+    let value = path.value();
+    let names: Vec<_> = value.split('.').map(str::to_owned).collect();
+    if names.iter().all(|name| is_identifier(name)) {
+        Ok(names)
+    } else {
+        Err(syn::Error::new(
+            path.span(),
+            format!("invalid attribute path `{value}`, expected identifiers separated by `.`, e.g. `state.pose.x`"),
+        ))
+    }
+}
@@ -0,0 +1,127 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{
+    spanned::Spanned, Data, DataEnum, DataStruct, DataUnion, DeriveInput, Fields, Index, Member,
+};
+
+use crate::{
+    attr::{ContainerAttrs, FieldAttrs},
+    bound::with_bound,
+    error::{unsupported_shape, Errors},
+};
+
+pub fn impl_into_pytuple(input: DeriveInput) -> syn::Result<TokenStream> {
+    // The name of the struct for which `IntoPyTuple` is being derived
+    let struct_ident = &input.ident;
+
+    // The attributes of the struct for which `IntoPyTuple` is being derived
+    let mut errors = Errors::default();
+    let container_attrs = ContainerAttrs::from_input(&input, &mut errors);
+
+    // The fields of the struct for which `IntoPyTuple` is being derived (named fields must be explicitly enabled)
+    let fields = match &input.data {
+        Data::Struct(DataStruct { fields, .. }) => {
+            if let (Fields::Named(fields), false) = (fields, container_attrs.tuple) {
+                errors.push(syn::Error::new(
+                    fields.span(),
+                    format!("struct `{struct_ident}` with named fields can be converted into a tuple only with the `#[pyo3_more(tuple)]` attribute"),
+                ));
+            }
+            fields
+        }
+        Data::Enum(DataEnum { enum_token, .. }) => {
+            errors.push(unsupported_shape(
+                enum_token.span,
+                "IntoPyTuple",
+                struct_ident,
+                "a struct",
+            ));
+            return errors.finish(TokenStream::new());
+        }
+        Data::Union(DataUnion { union_token, .. }) => {
+            errors.push(unsupported_shape(
+                union_token.span,
+                "IntoPyTuple",
+                struct_ident,
+                "a struct",
+            ));
+            return errors.finish(TokenStream::new());
+        }
+    };
+
+    // The fields that are not skipped together with their member access (`self.name` or `self.0`)
+    let mut tuple_fields = Vec::with_capacity(fields.len());
+    for (index, field) in fields.iter().enumerate() {
+        let field_attrs = FieldAttrs::from_attrs(&field.attrs, &mut errors);
+        if let Some(rename) = &field_attrs.rename {
+            errors.push(unsupported_attr(rename.to_token_stream(), "rename"));
+        }
+        if let Some(skip_if) = &field_attrs.skip_if {
+            errors.push(unsupported_attr(skip_if.to_token_stream(), "skip_if"));
+        }
+        if let Some(with) = &field_attrs.with {
+            errors.push(unsupported_attr(with.to_token_stream(), "with"));
+        }
+        if field_attrs.flatten {
+            errors.push(unsupported_attr(field.to_token_stream(), "flatten"));
+        }
+        if field_attrs.nested {
+            errors.push(unsupported_attr(field.to_token_stream(), "nested"));
+        }
+        if let Some(path) = &field_attrs.path {
+            errors.push(unsupported_attr(path.to_token_stream(), "path"));
+        }
+        if field_attrs.default {
+            errors.push(unsupported_attr(field.to_token_stream(), "default"));
+        }
+        if field_attrs.skip {
+            continue;
+        }
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(index)),
+        };
+        tuple_fields.push((member, &field.ty));
+    }
+    errors.finish(())?;
+
+    // The generics of the struct with `IntoPy<PyObject>` bounds for the type parameters of converted fields
+    let generics = with_bound(
+        &input.generics,
+        tuple_fields.iter().map(|(_, ty)| *ty),
+        &syn::parse_quote!(::pyo3::IntoPy<::pyo3::PyObject>),
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let members = tuple_fields.iter().map(|(member, _)| member);
+    let len = tuple_fields.len();
+
+    // The generated implementation
+    Ok(quote::quote! {
+        #[automatically_derived]
+        impl #impl_generics ::pyo3::IntoPy<::pyo3::Py<::pyo3::types::PyTuple>> for #struct_ident #ty_generics #where_clause {
+            fn into_py(self, py: ::pyo3::Python) -> ::pyo3::Py<::pyo3::types::PyTuple> {
+                let elements: [::pyo3::PyObject; #len] = [
+                    #(::pyo3::IntoPy::<::pyo3::PyObject>::into_py(self.#members, py),)*
+                ];
+                ::pyo3::types::PyTuple::new(py, elements).into()
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::std::convert::From<#struct_ident #ty_generics> for ::pyo3::Py<::pyo3::types::PyTuple> #where_clause {
+            fn from(value: #struct_ident #ty_generics) -> Self {
+                ::pyo3::Python::with_gil(|py| {
+                    ::pyo3::IntoPy::<::pyo3::Py<::pyo3::types::PyTuple>>::into_py(value, py)
+                })
+            }
+        }
+    })
+}
+
+/// Error for a field attribute that has no meaning for fields converted into a tuple
+fn unsupported_attr(tokens: TokenStream, key: &str) -> syn::Error {
+    syn::Error::new_spanned(
+        tokens,
+        format!("`{key}` attribute is not supported for fields converted into a tuple"),
+    )
+}
@@ -0,0 +1,20 @@
+// Each test crate uses only some of the helpers
+#![allow(dead_code)]
+
+use pyo3::prelude::*;
+
+/// Assert that a Python object equals the result of a Python expression
+#[track_caller]
+pub fn assert_py_eq(actual: &PyAny, expected: &str) {
+    let py = actual.py();
+    let expected_value = py.eval(expected, None, None).unwrap();
+    assert!(
+        actual.eq(expected_value).unwrap(),
+        "{actual} != {expected_value}"
+    );
+}
+
+/// Evaluate a Python expression
+pub fn eval<'py>(py: Python<'py>, expression: &str) -> &'py PyAny {
+    py.eval(expression, None, None).unwrap()
+}
@@ -0,0 +1,101 @@
+// Python cannot be embedded in the interpreter of Miri
+#![cfg(not(miri))]
+
+mod common;
+
+use common::assert_py_eq;
+use pyo3::{prelude::*, types::IntoPyDict as _};
+use pyo3_derive_more::{IntoPyDict, PyDataclass};
+
+#[derive(PyDataclass)]
+#[pyo3_more(rename_all = "camelCase")]
+struct Config {
+    learning_rate: f64,
+    layer_names: Vec<String>,
+    seed: Option<u64>,
+    optimizer: Optimizer,
+    #[pyo3_more(skip)]
+    _cache: Vec<u8>,
+}
+
+/// The dataclass ignores the `extra` items of `IntoPyDict`
+#[derive(PyDataclass, IntoPyDict)]
+#[pyo3_more(extra(key = "kind", with = "Self::kind"))]
+struct Optimizer {
+    name: String,
+}
+
+impl Optimizer {
+    fn kind(&self) -> &'static str {
+        "optimizer"
+    }
+}
+
+#[test]
+fn into_py_dataclass() {
+    Python::with_gil(|py| {
+        let config = Config {
+            learning_rate: 0.1,
+            layer_names: vec!["conv".to_owned()],
+            seed: None,
+            optimizer: Optimizer {
+                name: "adam".to_owned(),
+            },
+            _cache: Vec::new(),
+        };
+        let instance = config.try_into_py_dataclass(py).unwrap();
+        assert_py_eq(instance.getattr("learningRate").unwrap(), "0.1");
+        assert_py_eq(
+            instance
+                .getattr("optimizer")
+                .unwrap()
+                .getattr("name")
+                .unwrap(),
+            "'adam'",
+        );
+
+        let dataclasses = py.import("dataclasses").unwrap();
+        let as_dict = dataclasses
+            .getattr("asdict")
+            .unwrap()
+            .call1((instance,))
+            .unwrap();
+        assert_py_eq(
+            as_dict,
+            "{'learningRate': 0.1, 'layerNames': ['conv'], 'seed': None, 'optimizer': {'name': 'adam'}}",
+        );
+
+        // The struct can also derive `IntoPyDict`
+        let optimizer = Optimizer {
+            name: "sgd".to_owned(),
+        };
+        assert_py_eq(
+            optimizer.into_py_dict(py),
+            "{'name': 'sgd', 'kind': 'optimizer'}",
+        );
+
+        // The type is created once with annotations that mirror the Rust types
+        let dataclass = Config::py_dataclass(py).unwrap();
+        assert!(dataclass.is(Config::py_dataclass(py).unwrap()));
+        assert!(instance.is_instance(dataclass).unwrap());
+        let fields = dataclasses
+            .getattr("fields")
+            .unwrap()
+            .call1((dataclass,))
+            .unwrap();
+        let types: Vec<String> = fields
+            .iter()
+            .unwrap()
+            .map(|field| field.unwrap().getattr("type").unwrap().to_string())
+            .collect();
+        assert_eq!(
+            types,
+            [
+                "<class 'float'>",
+                "typing.List[str]",
+                "typing.Optional[int]",
+                "typing.Any",
+            ]
+        );
+    });
+}
@@ -0,0 +1,506 @@
+// Python cannot be embedded in the interpreter of Miri
+#![cfg(not(miri))]
+
+mod common;
+
+use common::{assert_py_eq, eval};
+use pyo3::{
+    prelude::*,
+    types::{IntoPyDict as _, PyDict},
+};
+use pyo3_derive_more::{FromPyDict, IntoPyDict, ToPyDict};
+
+#[derive(IntoPyDict)]
+#[pyo3_more(rename_all = "camelCase")]
+struct Optimizer {
+    learning_rate: f64,
+    #[pyo3_more(rename = "weight-decay")]
+    weight_decay: f64,
+    #[pyo3_more(skip)]
+    _cache: Vec<u8>,
+    #[pyo3_more(skip_if = "Option::is_none")]
+    momentum: Option<f64>,
+    r#type: &'static str,
+}
+
+#[test]
+fn into_py_dict() {
+    Python::with_gil(|py| {
+        let optimizer = Optimizer {
+            learning_rate: 0.1,
+            weight_decay: 0.01,
+            _cache: vec![1, 2],
+            momentum: None,
+            r#type: "sgd",
+        };
+        assert_py_eq(
+            optimizer.into_py_dict(py),
+            "{'learningRate': 0.1, 'weight-decay': 0.01, 'type': 'sgd'}",
+        );
+
+        let optimizer = Optimizer {
+            learning_rate: 0.1,
+            weight_decay: 0.01,
+            _cache: Vec::new(),
+            momentum: Some(0.9),
+            r#type: "sgd",
+        };
+        assert_py_eq(
+            optimizer.try_into_py_dict(py).unwrap(),
+            "{'learningRate': 0.1, 'weight-decay': 0.01, 'momentum': 0.9, 'type': 'sgd'}",
+        );
+    });
+}
+
+#[test]
+fn try_from_into_py() {
+    let optimizer = Optimizer {
+        learning_rate: 0.1,
+        weight_decay: 0.0,
+        _cache: Vec::new(),
+        momentum: None,
+        r#type: "adam",
+    };
+    let dict = Py::<PyDict>::try_from(optimizer).unwrap();
+    Python::with_gil(|py| {
+        assert_py_eq(
+            dict.as_ref(py),
+            "{'learningRate': 0.1, 'weight-decay': 0.0, 'type': 'adam'}",
+        );
+    });
+}
+
+#[derive(IntoPyDict, ToPyDict, FromPyDict, Debug, Clone, PartialEq)]
+struct Device {
+    seed: u64,
+    device: String,
+}
+
+#[derive(IntoPyDict, ToPyDict, FromPyDict, Debug, Clone, PartialEq)]
+#[pyo3_more(strip_trailing_underscore)]
+struct Params<T> {
+    lambda_: T,
+    #[pyo3_more(flatten)]
+    device: Device,
+    #[pyo3_more(nested)]
+    layers: Vec<Device>,
+    dropout: Option<f64>,
+}
+
+#[test]
+fn round_trip() {
+    Python::with_gil(|py| {
+        let params = Params {
+            lambda_: 1.5,
+            device: Device {
+                seed: 1,
+                device: "cpu".to_owned(),
+            },
+            layers: vec![Device {
+                seed: 2,
+                device: "cuda".to_owned(),
+            }],
+            dropout: None,
+        };
+        let expected =
+            "{'lambda': 1.5, 'layers': [{'seed': 2, 'device': 'cuda'}], 'dropout': None, 'seed': 1, 'device': 'cpu'}";
+        let dict = params.to_py_dict(py);
+        assert_py_eq(dict, expected);
+        assert_py_eq(params.clone().into_py_dict(py), expected);
+        assert_eq!(Params::try_from(dict).unwrap(), params);
+
+        // Missing keys of `Option` fields are extracted as `None`
+        dict.del_item("dropout").unwrap();
+        assert_eq!(Params::try_from(dict).unwrap(), params);
+
+        // Owned dictionaries can be extracted as well
+        let dict: Py<PyDict> = dict.into();
+        assert_eq!(Params::<f64>::try_from(dict).unwrap(), params);
+    });
+}
+
+#[test]
+fn from_py_dict_errors() {
+    Python::with_gil(|py| {
+        let dict = eval(py, "{'seed': 1}").downcast::<PyDict>().unwrap();
+        let err = Device::try_from(dict).unwrap_err();
+        assert!(err.is_instance_of::<pyo3::exceptions::PyKeyError>(py));
+        assert_eq!(
+            err.value(py).to_string(),
+            "\"Missing key 'device' required by field 'device' of struct 'Device'.\""
+        );
+
+        let dict = eval(py, "{'seed': 'one', 'device': 'cpu'}")
+            .downcast::<PyDict>()
+            .unwrap();
+        let err = Device::try_from(dict).unwrap_err();
+        assert!(err.is_instance_of::<pyo3::exceptions::PyTypeError>(py));
+        assert_eq!(
+            err.value(py).to_string(),
+            "Failed to extract key 'seed' of struct 'Device' from a pyo3::types::PyDict item."
+        );
+        assert!(err.cause(py).is_some());
+    });
+}
+
+#[derive(IntoPyDict)]
+struct Borrowed<'a, T> {
+    name: &'a str,
+    values: Vec<T>,
+}
+
+#[test]
+fn lifetimes() {
+    Python::with_gil(|py| {
+        let name = String::from("a");
+        let borrowed = Borrowed {
+            name: &name,
+            values: vec![1, 2],
+        };
+        assert_py_eq(borrowed.into_py_dict(py), "{'name': 'a', 'values': [1, 2]}");
+    });
+}
+
+#[derive(IntoPyDict, ToPyDict, FromPyDict, Debug, PartialEq)]
+#[pyo3_more(tag = "type", rename_all = "snake_case")]
+enum Scheduler {
+    StepDecay {
+        gamma: f64,
+    },
+    #[pyo3_more(rename = "cosine", rename_all = "camelCase")]
+    Cosine {
+        max_steps: u32,
+    },
+    Constant,
+    Device(Device),
+}
+
+#[test]
+fn internally_tagged_enum() {
+    Python::with_gil(|py| {
+        let cases = [
+            (
+                Scheduler::StepDecay { gamma: 0.5 },
+                "{'type': 'step_decay', 'gamma': 0.5}",
+            ),
+            (
+                Scheduler::Cosine { max_steps: 10 },
+                "{'type': 'cosine', 'maxSteps': 10}",
+            ),
+            (Scheduler::Constant, "{'type': 'constant'}"),
+            (
+                Scheduler::Device(Device {
+                    seed: 3,
+                    device: "cpu".to_owned(),
+                }),
+                "{'type': 'device', 'seed': 3, 'device': 'cpu'}",
+            ),
+        ];
+        for (scheduler, expected) in cases {
+            let dict = scheduler.to_py_dict(py);
+            assert_py_eq(dict, expected);
+            assert_eq!(Scheduler::try_from(dict).unwrap(), scheduler);
+            assert_py_eq(scheduler.into_py_dict(py), expected);
+        }
+
+        let dict = eval(py, "{'type': 'linear'}").downcast::<PyDict>().unwrap();
+        let err = Scheduler::try_from(dict).unwrap_err();
+        assert!(err.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+        assert_eq!(
+            err.value(py).to_string(),
+            "Unknown variant 'linear' of enum 'Scheduler', expected one of 'step_decay', 'cosine', 'constant', 'device'."
+        );
+
+        let dict = eval(py, "{'gamma': 0.5}").downcast::<PyDict>().unwrap();
+        let err = Scheduler::try_from(dict).unwrap_err();
+        assert!(err.is_instance_of::<pyo3::exceptions::PyKeyError>(py));
+    });
+}
+
+#[derive(IntoPyDict, FromPyDict, Debug, Clone, PartialEq)]
+#[pyo3_more(tag = "kind", content = "data")]
+enum Schedule {
+    Step { gamma: f64 },
+    Range(i32, i32),
+    Single(i32),
+    Unit,
+}
+
+#[test]
+fn adjacently_tagged_enum() {
+    Python::with_gil(|py| {
+        let cases = [
+            (
+                Schedule::Step { gamma: 0.5 },
+                "{'kind': 'Step', 'data': {'gamma': 0.5}}",
+            ),
+            (Schedule::Range(1, 2), "{'kind': 'Range', 'data': (1, 2)}"),
+            (Schedule::Single(3), "{'kind': 'Single', 'data': 3}"),
+            (Schedule::Unit, "{'kind': 'Unit'}"),
+        ];
+        for (schedule, expected) in cases {
+            let dict = schedule.clone().try_into_py_dict(py).unwrap();
+            assert_py_eq(dict, expected);
+            assert_eq!(Schedule::try_from(dict).unwrap(), schedule);
+        }
+    });
+}
+
+mod millis {
+    use pyo3::prelude::*;
+
+    pub fn to_py(seconds: &f64, py: Python) -> PyResult<PyObject> {
+        Ok((seconds * 1000.0).into_py(py))
+    }
+
+    pub fn from_py(millis: &PyAny) -> PyResult<f64> {
+        Ok(millis.extract::<f64>()? / 1000.0)
+    }
+}
+
+#[derive(IntoPyDict, ToPyDict, FromPyDict, Debug, PartialEq)]
+#[pyo3_more(extra(key = "total_steps", with = "Self::total_steps"))]
+struct Training {
+    epochs: u32,
+    steps_per_epoch: u32,
+    #[pyo3_more(with = "millis")]
+    timeout: f64,
+}
+
+impl Training {
+    fn total_steps(&self) -> u32 {
+        self.epochs * self.steps_per_epoch
+    }
+}
+
+#[test]
+fn extra_and_with() {
+    Python::with_gil(|py| {
+        let training = Training {
+            epochs: 2,
+            steps_per_epoch: 3,
+            timeout: 1.5,
+        };
+        let dict = training.to_py_dict(py);
+        assert_py_eq(
+            dict,
+            "{'epochs': 2, 'steps_per_epoch': 3, 'timeout': 1500.0, 'total_steps': 6}",
+        );
+        assert_eq!(Training::try_from(dict).unwrap(), training);
+    });
+}
+
+#[derive(IntoPyDict)]
+struct Colliding {
+    seed: u64,
+    #[pyo3_more(flatten)]
+    extra: Vec<(&'static str, i32)>,
+}
+
+#[test]
+fn flatten_collision() {
+    Python::with_gil(|py| {
+        let colliding = Colliding {
+            seed: 1,
+            extra: vec![("steps", 2)],
+        };
+        assert_py_eq(colliding.into_py_dict(py), "{'seed': 1, 'steps': 2}");
+
+        let colliding = Colliding {
+            seed: 1,
+            extra: vec![("seed", 2)],
+        };
+        let err = colliding.try_into_py_dict(py).unwrap_err();
+        assert!(err.is_instance_of::<pyo3::exceptions::PyKeyError>(py));
+        assert_eq!(
+            err.value(py).to_string(),
+            "\"Key 'seed' of flattened field 'extra' of struct 'Colliding' collides with another key.\""
+        );
+    });
+}
+
+mod strict {
+    use pyo3::prelude::*;
+
+    pub fn to_py(value: &i32, py: Python) -> PyResult<PyObject> {
+        // An exception whose constructor requires two arguments
+        let exception = py.eval(
+            "type('PairError', (Exception,), {'__init__': lambda self, a, b: Exception.__init__(self, a, b)})",
+            None,
+            None,
+        )?;
+        Err(PyErr::from_value(exception.call1((*value, "b"))?))
+    }
+}
+
+#[derive(IntoPyDict, ToPyDict)]
+struct Strict {
+    #[pyo3_more(with = "strict")]
+    value: i32,
+}
+
+#[test]
+fn error_context() {
+    Python::with_gil(|py| {
+        // The original type is kept if it can be constructed from the message
+        let training = Training {
+            epochs: 1,
+            steps_per_epoch: 1,
+            timeout: 1.0,
+        };
+        let dict = training.to_py_dict(py);
+        dict.set_item("timeout", "soon").unwrap();
+        let err = Training::try_from(dict).unwrap_err();
+        assert!(err.is_instance_of::<pyo3::exceptions::PyTypeError>(py));
+        assert_eq!(
+            err.value(py).to_string(),
+            "Failed to extract key 'timeout' of struct 'Training' from a pyo3::types::PyDict item."
+        );
+
+        // Otherwise, the context is a `RuntimeError` that still names the field
+        let err = Strict { value: 1 }.try_into_py_dict(py).unwrap_err();
+        assert!(err.is_instance_of::<pyo3::exceptions::PyRuntimeError>(py));
+        assert_eq!(
+            err.value(py).to_string(),
+            "Failed to convert field 'value' of struct 'Strict' into a pyo3::types::PyDict item."
+        );
+        let cause = err.cause(py).unwrap();
+        assert_eq!(cause.get_type(py).name().unwrap(), "PairError");
+        assert_eq!(cause.value(py).to_string(), "(1, 'b')");
+    });
+}
+
+#[derive(IntoPyDict, ToPyDict, FromPyDict, Debug, Clone, PartialEq)]
+struct Layer {
+    units: u32,
+}
+
+#[derive(IntoPyDict, ToPyDict, FromPyDict, Debug, Clone, PartialEq)]
+struct Network<T> {
+    #[pyo3_more(nested)]
+    input: Layer,
+    #[pyo3_more(nested)]
+    hidden: Vec<Layer>,
+    #[pyo3_more(nested)]
+    named: std::collections::BTreeMap<String, Vec<T>>,
+    #[pyo3_more(nested)]
+    output: Option<Layer>,
+}
+
+#[pyo3::pyclass]
+#[derive(IntoPyDict)]
+struct Neuron {
+    bias: f64,
+}
+
+#[derive(IntoPyDict)]
+struct Boxed {
+    #[pyo3_more(nested)]
+    neurons: [Box<Neuron>; 2],
+}
+
+#[test]
+fn nested() {
+    Python::with_gil(|py| {
+        let network = Network {
+            input: Layer { units: 1 },
+            hidden: vec![Layer { units: 2 }, Layer { units: 3 }],
+            named: [("head".to_owned(), vec![Layer { units: 4 }])].into(),
+            output: None,
+        };
+        let expected = "{'input': {'units': 1}, 'hidden': [{'units': 2}, {'units': 3}], 'named': {'head': [{'units': 4}]}, 'output': None}";
+        let dict = network.clone().into_py_dict(py);
+        assert_py_eq(dict, expected);
+        assert_py_eq(network.to_py_dict(py), expected);
+        assert_eq!(Network::try_from(dict).unwrap(), network);
+
+        // The derived types can still be Python classes
+        let boxed = Boxed {
+            neurons: [
+                Box::new(Neuron { bias: 0.5 }),
+                Box::new(Neuron { bias: 1.5 }),
+            ],
+        };
+        assert_py_eq(
+            boxed.into_py_dict(py),
+            "{'neurons': [{'bias': 0.5}, {'bias': 1.5}]}",
+        );
+        let neuron = Py::new(py, Neuron { bias: 2.5 }).unwrap();
+        assert_eq!(neuron.borrow(py).bias, 2.5);
+    });
+}
+
+#[derive(IntoPyDict, ToPyDict)]
+struct StrictLayers {
+    #[pyo3_more(nested)]
+    layers: Vec<Strict>,
+}
+
+#[test]
+fn nested_error() {
+    Python::with_gil(|py| {
+        // The error of a nested dictionary is returned instead of panicking
+        let layers = StrictLayers {
+            layers: vec![Strict { value: 1 }],
+        };
+        for err in [
+            layers.try_to_py_dict(py).unwrap_err(),
+            layers.try_into_py_dict(py).unwrap_err(),
+        ] {
+            assert!(err.is_instance_of::<pyo3::exceptions::PyRuntimeError>(py));
+            assert_eq!(
+                err.value(py).to_string(),
+                "Failed to convert field 'layers' of struct 'StrictLayers' into a pyo3::types::PyDict item."
+            );
+            let cause = err.cause(py).unwrap();
+            assert_eq!(
+                cause.value(py).to_string(),
+                "Failed to convert field 'value' of struct 'Strict' into a pyo3::types::PyDict item."
+            );
+        }
+    });
+}
+
+#[derive(IntoPyDict, ToPyDict)]
+struct StrictFlattened {
+    #[pyo3_more(flatten)]
+    strict: Strict,
+}
+
+#[derive(IntoPyDict, ToPyDict)]
+#[pyo3_more(tag = "type")]
+enum StrictVariant {
+    Strict(Strict),
+}
+
+#[test]
+fn merged_error() {
+    Python::with_gil(|py| {
+        // The errors of flattened fields and merged newtype variants are returned instead of panicking
+        let flattened = StrictFlattened {
+            strict: Strict { value: 1 },
+        };
+        for err in [
+            flattened.try_to_py_dict(py).unwrap_err(),
+            flattened.try_into_py_dict(py).unwrap_err(),
+        ] {
+            assert_eq!(
+                err.value(py).to_string(),
+                "Failed to convert field 'strict' of struct 'StrictFlattened' into a pyo3::types::PyDict item."
+            );
+            assert!(err.cause(py).is_some());
+        }
+
+        let variant = StrictVariant::Strict(Strict { value: 1 });
+        for err in [
+            variant.try_to_py_dict(py).unwrap_err(),
+            variant.try_into_py_dict(py).unwrap_err(),
+        ] {
+            assert_eq!(
+                err.value(py).to_string(),
+                "Failed to convert the content of variant 'StrictVariant::Strict' into a pyo3::types::PyDict item."
+            );
+            assert!(err.cause(py).is_some());
+        }
+    });
+}
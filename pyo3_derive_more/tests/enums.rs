@@ -0,0 +1,138 @@
+// Python cannot be embedded in the interpreter of Miri
+#![cfg(not(miri))]
+
+mod common;
+
+use common::{assert_py_eq, eval};
+use pyo3::{prelude::*, types::IntoPyDict as _};
+use pyo3_derive_more::{FromPyDict, IntoPyDict, PyEnum, PyStrEnum};
+
+#[derive(PyEnum, Debug, Clone, Copy, PartialEq)]
+#[pyo3_more(rename_all = "SCREAMING_SNAKE_CASE")]
+enum Color {
+    Red,
+    #[pyo3_more(alias = "NAVY")]
+    DarkBlue = 5,
+}
+
+#[test]
+fn py_enum() {
+    Python::with_gil(|py| {
+        let member = Color::DarkBlue.into_py(py);
+        let member = member.as_ref(py);
+        assert_py_eq(member.getattr("name").unwrap(), "'DARK_BLUE'");
+        assert_py_eq(member.getattr("value").unwrap(), "5");
+        assert_eq!(member.extract::<Color>().unwrap(), Color::DarkBlue);
+        assert!(member.get_type().is(Color::py_enum(py).unwrap()));
+        assert_py_eq(
+            Color::Red.to_py_enum(py).unwrap().getattr("value").unwrap(),
+            "0",
+        );
+
+        let err = eval(py, "'RED'").extract::<Color>().unwrap_err();
+        assert!(err.is_instance_of::<pyo3::exceptions::PyTypeError>(py));
+        assert_eq!(
+            err.value(py).to_string(),
+            "Expected a member of enum 'Color', got 'str'."
+        );
+    });
+}
+
+#[derive(PyEnum, Debug, PartialEq)]
+#[pyo3_more(class = "signal.Signals")]
+enum Signal {
+    #[pyo3_more(rename = "SIGINT")]
+    Interrupt,
+    #[pyo3_more(rename = "SIGTERM")]
+    Terminate,
+}
+
+#[test]
+fn py_enum_class() {
+    Python::with_gil(|py| {
+        let member = Signal::Terminate.into_py(py);
+        assert_py_eq(member.as_ref(py), "__import__('signal').SIGTERM");
+        assert_eq!(member.extract::<Signal>(py).unwrap(), Signal::Terminate);
+        let member = eval(py, "__import__('signal').SIGINT");
+        assert_eq!(member.extract::<Signal>().unwrap(), Signal::Interrupt);
+
+        let err = eval(py, "__import__('signal').SIGABRT")
+            .extract::<Signal>()
+            .unwrap_err();
+        assert!(err.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+        assert_eq!(
+            err.value(py).to_string(),
+            "Unknown member 'SIGABRT' of enum 'signal.Signals', expected one of 'SIGINT', 'SIGTERM'."
+        );
+    });
+}
+
+#[derive(PyStrEnum, Debug, Clone, Copy, PartialEq)]
+#[pyo3_more(rename_all = "lowercase")]
+enum Interpolation {
+    Nearest,
+    #[pyo3_more(alias = "linear")]
+    Bilinear,
+}
+
+#[derive(IntoPyDict, FromPyDict, Debug, PartialEq)]
+struct Resize {
+    width: u32,
+    mode: Interpolation,
+}
+
+#[test]
+fn py_str_enum() {
+    Python::with_gil(|py| {
+        assert_eq!(Interpolation::Bilinear.as_py_str(), "bilinear");
+        assert_py_eq(Interpolation::Nearest.into_py(py).as_ref(py), "'nearest'");
+        assert_eq!(
+            eval(py, "'linear'").extract::<Interpolation>().unwrap(),
+            Interpolation::Bilinear
+        );
+
+        let err = eval(py, "'cubic'").extract::<Interpolation>().unwrap_err();
+        assert!(err.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+        assert_eq!(
+            err.value(py).to_string(),
+            "Invalid value 'cubic' of enum 'Interpolation', expected one of 'nearest', 'bilinear'."
+        );
+        let err = eval(py, "1").extract::<Interpolation>().unwrap_err();
+        assert!(err.is_instance_of::<pyo3::exceptions::PyTypeError>(py));
+
+        let resize = Resize {
+            width: 640,
+            mode: Interpolation::Nearest,
+        };
+        let dict = resize.into_py_dict(py);
+        assert_py_eq(dict, "{'width': 640, 'mode': 'nearest'}");
+        assert_eq!(
+            Resize::try_from(dict).unwrap(),
+            Resize {
+                width: 640,
+                mode: Interpolation::Nearest
+            }
+        );
+    });
+}
+
+/// The attributes of other derives are ignored, so that they can share an enum
+#[derive(PyEnum, IntoPyDict, Debug, Clone, Copy, PartialEq)]
+#[pyo3_more(tag = "kind", rename_all = "lowercase")]
+enum Activation {
+    Relu,
+    Tanh,
+}
+
+#[test]
+fn shared_attributes() {
+    Python::with_gil(|py| {
+        let member = Activation::Tanh.into_py(py);
+        assert_py_eq(member.as_ref(py).getattr("name").unwrap(), "'tanh'");
+        assert_eq!(member.extract::<Activation>(py).unwrap(), Activation::Tanh);
+        assert_py_eq(
+            Activation::Relu.try_into_py_dict(py).unwrap(),
+            "{'kind': 'relu'}",
+        );
+    });
+}
@@ -0,0 +1,80 @@
+// Python cannot be embedded in the interpreter of Miri
+#![cfg(not(miri))]
+
+mod common;
+
+use common::assert_py_eq;
+use pyo3::prelude::*;
+use pyo3_derive_more::{IntoPyDict, IntoPyInstance};
+
+#[derive(IntoPyInstance)]
+#[pyo3_more(class = "fractions.Fraction")]
+struct Fraction {
+    numerator: i32,
+    denominator: i32,
+}
+
+#[derive(IntoPyInstance)]
+#[pyo3_more(class = "[json.decoder].JSONDecoder")]
+struct Decoder {
+    strict: bool,
+    #[pyo3_more(skip_if = "Option::is_none")]
+    object_hook: Option<PyObject>,
+}
+
+/// The attributes of other derives are ignored, so that they can share a struct
+#[derive(IntoPyInstance, IntoPyDict)]
+#[pyo3_more(class = "fractions.Fraction")]
+struct Ratio {
+    numerator: i32,
+    denominator: i32,
+}
+
+#[test]
+fn into_py_instance() {
+    Python::with_gil(|py| {
+        let fraction = Fraction {
+            numerator: 2,
+            denominator: 4,
+        };
+        let instance = fraction.try_into_py_instance(py).unwrap();
+        assert_py_eq(instance, "__import__('fractions').Fraction(1, 2)");
+
+        let decoder = Decoder {
+            strict: false,
+            object_hook: None,
+        };
+        let instance = decoder.into_py(py);
+        assert_py_eq(instance.getattr(py, "strict").unwrap().as_ref(py), "False");
+        assert_py_eq(
+            instance.getattr(py, "object_hook").unwrap().as_ref(py),
+            "None",
+        );
+
+        let ratio = Ratio {
+            numerator: 3,
+            denominator: 6,
+        };
+        let dict = ratio.try_into_py_dict(py).unwrap();
+        assert_py_eq(dict, "{'numerator': 3, 'denominator': 6}");
+        let ratio = Ratio {
+            numerator: 3,
+            denominator: 6,
+        };
+        assert_py_eq(
+            ratio.into_py(py).as_ref(py),
+            "__import__('fractions').Fraction(1, 2)",
+        );
+
+        let fraction = Fraction {
+            numerator: 1,
+            denominator: 0,
+        };
+        let err = fraction.try_into_py_instance(py).unwrap_err();
+        assert!(err.is_instance_of::<pyo3::exceptions::PyZeroDivisionError>(py));
+        assert_eq!(
+            err.value(py).to_string(),
+            "Failed to construct an instance of 'fractions.Fraction' from 'Fraction'."
+        );
+    });
+}
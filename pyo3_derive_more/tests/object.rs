@@ -0,0 +1,87 @@
+// Python cannot be embedded in the interpreter of Miri
+#![cfg(not(miri))]
+
+mod common;
+
+use common::{assert_py_eq, eval};
+use pyo3::prelude::*;
+use pyo3_derive_more::{ApplyToPyObject, FromPyAttrs};
+
+#[derive(FromPyAttrs, ApplyToPyObject, Debug, Clone, PartialEq)]
+#[pyo3_more(diff)]
+struct State {
+    step: u32,
+    #[pyo3_more(path = "pose.x")]
+    x: f64,
+    #[pyo3_more(rename = "name")]
+    label: String,
+    #[pyo3_more(default)]
+    done: bool,
+}
+
+const SIMULATOR: &str = "__import__('types').SimpleNamespace(step=3, name='sim', pose=__import__('types').SimpleNamespace(x=1.5))";
+
+#[test]
+fn from_py_attrs() {
+    Python::with_gil(|py| {
+        let simulator = eval(py, SIMULATOR);
+        let expected = State {
+            step: 3,
+            x: 1.5,
+            label: "sim".to_owned(),
+            done: false,
+        };
+        assert_eq!(State::from_py_attrs(simulator).unwrap(), expected);
+        assert_eq!(State::try_from(simulator).unwrap(), expected);
+
+        simulator.setattr("step", "three").unwrap();
+        let err = State::from_py_attrs(simulator).unwrap_err();
+        assert!(err.is_instance_of::<pyo3::exceptions::PyTypeError>(py));
+        assert_eq!(
+            err.value(py).to_string(),
+            "Failed to extract attribute 'step' required by field 'step' of struct 'State'."
+        );
+
+        simulator.setattr("step", 3).unwrap();
+        simulator.delattr("pose").unwrap();
+        let err = State::from_py_attrs(simulator).unwrap_err();
+        assert!(err.is_instance_of::<pyo3::exceptions::PyAttributeError>(py));
+        assert_eq!(
+            err.value(py).to_string(),
+            "Failed to extract attribute 'pose.x' required by field 'x' of struct 'State'."
+        );
+    });
+}
+
+#[test]
+fn apply_to_py_object() {
+    Python::with_gil(|py| {
+        let simulator = eval(py, SIMULATOR);
+        let state = State {
+            step: 4,
+            x: 2.5,
+            label: "sim".to_owned(),
+            done: true,
+        };
+        state.apply_to_py_object(simulator).unwrap();
+        assert_py_eq(simulator.getattr("step").unwrap(), "4");
+        assert_py_eq(
+            simulator.getattr("pose").unwrap().getattr("x").unwrap(),
+            "2.5",
+        );
+        assert_py_eq(simulator.getattr("done").unwrap(), "True");
+
+        // Only the fields that changed are set
+        simulator.setattr("name", "overwritten").unwrap();
+        let next = State {
+            step: 5,
+            ..state.clone()
+        };
+        next.apply_diff_to_py_object(Some(&state), simulator)
+            .unwrap();
+        assert_py_eq(simulator.getattr("step").unwrap(), "5");
+        assert_py_eq(simulator.getattr("name").unwrap(), "'overwritten'");
+        next.apply_diff_to_py_object(None, simulator).unwrap();
+        assert_py_eq(simulator.getattr("name").unwrap(), "'sim'");
+    });
+}
@@ -0,0 +1,63 @@
+// Python cannot be embedded in the interpreter of Miri
+#![cfg(not(miri))]
+
+mod common;
+
+use common::assert_py_eq;
+use pyo3::{prelude::*, types::PyTuple};
+use pyo3_derive_more::{IntoPyDict, IntoPyTuple};
+
+#[derive(IntoPyTuple)]
+struct Vec3(f64, f64, f64);
+
+#[derive(IntoPyTuple)]
+#[pyo3_more(tuple)]
+struct Resize<T> {
+    width: T,
+    #[pyo3_more(skip)]
+    _cache: Vec<u8>,
+    height: T,
+    mode: &'static str,
+}
+
+/// The attributes of other derives are ignored, so that they can share a struct
+#[derive(IntoPyTuple, IntoPyDict)]
+#[pyo3_more(tuple)]
+struct Size {
+    width: u32,
+    height: u32,
+}
+
+#[test]
+fn into_py_tuple() {
+    Python::with_gil(|py| {
+        let tuple: Py<PyTuple> = Vec3(1.0, 2.0, 3.0).into_py(py);
+        assert_py_eq(tuple.as_ref(py), "(1.0, 2.0, 3.0)");
+
+        let resize = Resize {
+            width: 640,
+            _cache: Vec::new(),
+            height: 480,
+            mode: "bilinear",
+        };
+        let tuple: Py<PyTuple> = resize.into_py(py);
+        assert_py_eq(tuple.as_ref(py), "(640, 480, 'bilinear')");
+
+        let size = Size {
+            width: 2,
+            height: 1,
+        };
+        let tuple: Py<PyTuple> = size.into_py(py);
+        assert_py_eq(tuple.as_ref(py), "(2, 1)");
+        let size = Size {
+            width: 2,
+            height: 1,
+        };
+        assert_py_eq(
+            size.try_into_py_dict(py).unwrap(),
+            "{'width': 2, 'height': 1}",
+        );
+    });
+    let tuple = Py::<PyTuple>::from(Vec3(0.0, 0.0, 1.0));
+    Python::with_gil(|py| assert_py_eq(tuple.as_ref(py), "(0.0, 0.0, 1.0)"));
+}
@@ -6,8 +6,57 @@
 //! `import omni.isaac.kit` works but `from omni.isaac import kit` might fail.
 
 /// Bind a Python callable to a Rust function.
+///
+/// The arguments are passed by keyword, unless they are followed by `/` as in a Python signature,
+/// e.g. `fn dot(a: A, b: B, /, *, out: O)` passes `a` and `b` positionally. The `*` marker documents
+/// that the following arguments are keyword-only.
+///
+/// Optional arguments passed by keyword can be declared with `= None`, e.g. `dtype: Option<T> = None`,
+/// to omit them from the call if they are `None`, so that the default value of the Python parameter applies.
+///
+/// With `static` before the path of the callable, e.g. `static [sim].world.step() => fn step()`, the
+/// callable is resolved only once and then reused in subsequent calls, until
+/// [`invalidate_cached_bindings()`](crate::invalidate_cached_bindings) is called (e.g. after a module is reloaded).
+///
+/// Variadic arguments are passed through with `*args: impl IntoPy<Py<PyTuple>>` after the positional
+/// arguments (which are all passed positionally in this case), and with `**kwargs: T` as the last argument,
+/// where `T` implements [`TryIntoPyDict`](crate::TryIntoPyDict), e.g. `&PyDict`, `HashMap<String, V>` or
+/// an option struct that derives `pyo3_derive_more::IntoPyDict`. The items of `**kwargs` are merged after
+/// the named arguments, and a key that collides with a named argument returns `TypeError`, while an item
+/// that fails to convert returns its error.
 #[macro_export]
 macro_rules! bind_python_callable {
+    // [static] Callable that is resolved only once: `static mod.**.callable() => fn(...)`
+    { $(#[$meta:meta])* static $module:ident$(.$callable:ident)+() => $($macro_tail:tt)+ } => {
+        $crate::bind_python_callable! {
+            $(#[$meta])*
+            static [$module]$(.$callable)+() => $($macro_tail)+
+        }
+    };
+    // [static, deep import, with GIL arg] Callable that is resolved only once: `static [mod.submod.**].**.callable() => fn(py: Python, ...)`
+    { $(#[$meta:meta])* static [$module:expr]$(.$callable:ident)+() => $vis:vis fn $fn_name:ident$(<$lf_fn:lifetime>)?($gil:ident: $(::pyo3::)?Python$(<$lf_python:lifetime>)?$(, $($args:tt)*)?) $(-> $(::pyo3::Py)?Result<$value:ty>)? } => {
+        $crate::bind_python_callable! {
+            @inner args {
+                { $(#[$meta])* $vis fn $fn_name$(<$lf_fn>)? }
+                { $gil: ::pyo3::Python$(<$lf_python>)?, }
+                { $gil }
+                { $crate::bind_python_callable!(@inner cached $gil { ::pyo3::types::PyModule::import($gil, ::pyo3::intern!($gil, stringify!($module)))?$(.getattr(::pyo3::intern!($gil, stringify!($callable)))?)+ }) }
+                { $($value)? }
+            } [] [] [] [] $($($args)*)?
+        }
+    };
+    // [static, deep import] Callable that is resolved only once: `static [mod.submod.**].**.callable() => fn(...)`
+    { $(#[$meta:meta])* static [$module:expr]$(.$callable:ident)+() => $vis:vis fn $fn_name:ident($($args:tt)*) $(-> $(::pyo3::Py)?Result<$value:ty>)? } => {
+        $crate::bind_python_callable! {
+            @inner args {
+                { $(#[$meta])* $vis fn $fn_name }
+                {}
+                { with_gil py }
+                { $crate::bind_python_callable!(@inner cached py { ::pyo3::types::PyModule::import(py, ::pyo3::intern!(py, stringify!($module)))?$(.getattr(::pyo3::intern!(py, stringify!($callable)))?)+ }) }
+                { $($value)? }
+            } [] [] [] [] $($args)*
+        }
+    };
     // [deep import, with GIL arg] Callable without arguments: `[mod.submod.**].**.callable(py: Python) => fn()`
     { $(#[$meta:meta])* [$module:expr]$(.$callable:ident)+() => $vis:vis fn $fn_name:ident$(<$lf_fn:lifetime>)?($gil:ident: $(::pyo3::)?Python$(<$lf_python:lifetime>)?) } => {
         $(#[$meta])*
@@ -27,55 +76,42 @@ macro_rules! bind_python_callable {
             callable.call0()?.extract()
         }
     };
-    // [with GIL arg] Callable with keyword arguments: `mod.**.callable() => fn(py: Python, arg: type, ...)
-    { $(#[$meta:meta])* $module:ident$(.$callable:ident)+() => $vis:vis fn $fn_name:ident$(<$lf_fn:lifetime>)?($gil:ident: $(::pyo3::)?Python$(<$lf_python:lifetime>)?$(, $arg:ident: $arg_type:ty)+) } => {
+    // [with GIL arg] Callable with arguments: `mod.**.callable() => fn(py: Python, arg: type, /, *, ...)`
+    { $(#[$meta:meta])* $module:ident$(.$callable:ident)+() => $vis:vis fn $fn_name:ident$(<$lf_fn:lifetime>)?($gil:ident: $(::pyo3::)?Python$(<$lf_python:lifetime>)?, $($args:tt)+) } => {
         $crate::bind_python_callable! {
             $(#[$meta])*
-            [$module]$(.$callable)+() => $vis fn $fn_name$(<$lf_fn>)?($gil: ::pyo3::Python$(<$lf_python>)?$(, $arg: $arg_type)+)
+            [$module]$(.$callable)+() => $vis fn $fn_name$(<$lf_fn>)?($gil: ::pyo3::Python$(<$lf_python>)?, $($args)+)
         }
     };
-    // [deep import, with GIL arg] Callable with keyword arguments: `[mod.submod.**].**.callable() => fn(arg: type, ...)
-    { $(#[$meta:meta])* [$module:expr]$(.$callable:ident)+() => $vis:vis fn $fn_name:ident$(<$lf_fn:lifetime>)?($gil:ident: $(::pyo3::)?Python$(<$lf_python:lifetime>)?$(, $arg:ident: $arg_type:ty)+) } => {
-        $(#[$meta])*
-        $vis fn $fn_name$(<$lf_fn>)?($gil: ::pyo3::Python$(<$lf_python>)?$(, $arg: $arg_type)*) -> ::pyo3::PyResult<()> {
-            let module: &::pyo3::PyAny = ::pyo3::types::PyModule::import($gil, ::pyo3::intern!($gil, stringify!($module)))?;
-            let callable = module$(.getattr(::pyo3::intern!($gil, stringify!($callable)))?)+;
-            let kwargs = ::pyo3::types::PyDict::new($gil);
-            $(
-                if stringify!($arg).starts_with("r#") {
-                    kwargs.set_item(stringify!($arg).trim_start_matches("r#"), $arg)?;
-                }
-                else {
-                    kwargs.set_item(::pyo3::intern!($gil, stringify!($arg)), $arg)?;
-                }
-            )+
-            callable.call((), Some(kwargs))?;
-            Ok(())
+    // [deep import, with GIL arg] Callable with arguments: `[mod.submod.**].**.callable() => fn(py: Python, arg: type, /, *, ...)`
+    { $(#[$meta:meta])* [$module:expr]$(.$callable:ident)+() => $vis:vis fn $fn_name:ident$(<$lf_fn:lifetime>)?($gil:ident: $(::pyo3::)?Python$(<$lf_python:lifetime>)?, $($args:tt)+) } => {
+        $crate::bind_python_callable! {
+            @inner args {
+                { $(#[$meta])* $vis fn $fn_name$(<$lf_fn>)? }
+                { $gil: ::pyo3::Python$(<$lf_python>)?, }
+                { $gil }
+                { ::pyo3::types::PyModule::import($gil, ::pyo3::intern!($gil, stringify!($module)))?$(.getattr(::pyo3::intern!($gil, stringify!($callable)))?)+ }
+                {}
+            } [] [] [] [] $($args)+
         }
     };
-    // [with GIL arg] Callable with keyword arguments: `mod.**.callable() => fn(py: Python, arg: type, ...) -> Result<value>`
-    { $(#[$meta:meta])* $module:ident$(.$callable:ident)+() => $vis:vis fn $fn_name:ident$(<$lf_fn:lifetime>)?($gil:ident: $(::pyo3::)?Python$(<$lf_python:lifetime>)?$(, $arg:ident: $arg_type:ty)+) -> $(::pyo3::Py)?Result<$value:ty> } => {
+    // [with GIL arg] Callable with arguments: `mod.**.callable() => fn(py: Python, arg: type, /, *, ...) -> Result<value>`
+    { $(#[$meta:meta])* $module:ident$(.$callable:ident)+() => $vis:vis fn $fn_name:ident$(<$lf_fn:lifetime>)?($gil:ident: $(::pyo3::)?Python$(<$lf_python:lifetime>)?, $($args:tt)+) -> $(::pyo3::Py)?Result<$value:ty> } => {
         $crate::bind_python_callable! {
             $(#[$meta])*
-            [$module]$(.$callable)+() => $vis fn $fn_name$(<$lf_fn>)?($gil: ::pyo3::Python$(<$lf_python>)?$(, $arg: $arg_type)+) -> Result<$value>
+            [$module]$(.$callable)+() => $vis fn $fn_name$(<$lf_fn>)?($gil: ::pyo3::Python$(<$lf_python>)?, $($args)+) -> Result<$value>
         }
     };
-    // [deep import, with GIL arg] Callable with keyword arguments: `[mod.submod.**].**.callable() => fn(arg: type, ...) -> Result<value>`
-    { $(#[$meta:meta])* [$module:expr]$(.$callable:ident)+() => $vis:vis fn $fn_name:ident$(<$lf_fn:lifetime>)?($gil:ident: $(::pyo3::)?Python$(<$lf_python:lifetime>)?$(, $arg:ident: $arg_type:ty)+) -> $(::pyo3::Py)?Result<$value:ty> } => {
-        $(#[$meta])*
-        $vis fn $fn_name$(<$lf_fn>)?($gil: ::pyo3::Python$(<$lf_python>)?$(, $arg: $arg_type)*) -> ::pyo3::PyResult<$value> {
-            let module: &::pyo3::PyAny = ::pyo3::types::PyModule::import($gil, ::pyo3::intern!($gil, stringify!($module)))?;
-            let callable = module$(.getattr(::pyo3::intern!($gil, stringify!($callable)))?)+;
-            let kwargs = ::pyo3::types::PyDict::new($gil);
-            $(
-                if stringify!($arg).starts_with("r#") {
-                    kwargs.set_item(stringify!($arg).trim_start_matches("r#"), $arg)?;
-                }
-                else {
-                    kwargs.set_item(::pyo3::intern!($gil, stringify!($arg)), $arg)?;
-                }
-            )+
-            callable.call((), Some(kwargs))?.extract()
+    // [deep import, with GIL arg] Callable with arguments: `[mod.submod.**].**.callable() => fn(py: Python, arg: type, /, *, ...) -> Result<value>`
+    { $(#[$meta:meta])* [$module:expr]$(.$callable:ident)+() => $vis:vis fn $fn_name:ident$(<$lf_fn:lifetime>)?($gil:ident: $(::pyo3::)?Python$(<$lf_python:lifetime>)?, $($args:tt)+) -> $(::pyo3::Py)?Result<$value:ty> } => {
+        $crate::bind_python_callable! {
+            @inner args {
+                { $(#[$meta])* $vis fn $fn_name$(<$lf_fn>)? }
+                { $gil: ::pyo3::Python$(<$lf_python>)?, }
+                { $gil }
+                { ::pyo3::types::PyModule::import($gil, ::pyo3::intern!($gil, stringify!($module)))?$(.getattr(::pyo3::intern!($gil, stringify!($callable)))?)+ }
+                { $value }
+            } [] [] [] [] $($args)+
         }
     };
     // Callable without arguments: `mod.**.callable() => fn()`
@@ -115,64 +151,189 @@ macro_rules! bind_python_callable {
             })
         }
     };
-    // Callable with keyword arguments: `mod.**.callable() => fn(arg: type, ...)
-    { $(#[$meta:meta])* $module:ident$(.$callable:ident)+() => $vis:vis fn $fn_name:ident($($arg:ident: $arg_type:ty),+) } => {
+    // Callable with arguments: `mod.**.callable() => fn(arg: type, /, *, ...)`
+    { $(#[$meta:meta])* $module:ident$(.$callable:ident)+() => $vis:vis fn $fn_name:ident($($args:tt)+) } => {
         $crate::bind_python_callable! {
             $(#[$meta])*
-            [$module]$(.$callable)+() => $vis fn $fn_name($($arg: $arg_type),+)
+            [$module]$(.$callable)+() => $vis fn $fn_name($($args)+)
         }
     };
-    // Callable with keyword arguments: `mod.**.callable() => fn(arg: type, ...) -> Result<value>`
-    { $(#[$meta:meta])* $module:ident$(.$callable:ident)+() => $vis:vis fn $fn_name:ident($($arg:ident: $arg_type:ty),+) -> $(::pyo3::Py)?Result<$value:ty> } => {
+    // Callable with arguments: `mod.**.callable() => fn(arg: type, /, *, ...) -> Result<value>`
+    { $(#[$meta:meta])* $module:ident$(.$callable:ident)+() => $vis:vis fn $fn_name:ident($($args:tt)+) -> $(::pyo3::Py)?Result<$value:ty> } => {
         $crate::bind_python_callable! {
             $(#[$meta])*
-            [$module]$(.$callable)+() => $vis fn $fn_name($($arg: $arg_type),+) -> Result<$value>
+            [$module]$(.$callable)+() => $vis fn $fn_name($($args)+) -> Result<$value>
         }
     };
-    // [deep import] Callable with keyword arguments: `[mod.submod.**].**.callable() => fn(arg: type, ...)
-    { $(#[$meta:meta])* [$module:expr]$(.$callable:ident)+() => $vis:vis fn $fn_name:ident($($arg:ident: $arg_type:ty),*) } => {
-        $(#[$meta])*
-        $vis fn $fn_name($($arg: $arg_type),+) -> ::pyo3::PyResult<()> {
-            ::pyo3::Python::with_gil(|py| {
-                let module: &::pyo3::PyAny = ::pyo3::types::PyModule::import(py, ::pyo3::intern!(py, stringify!($module)))?;
-                let callable = module$(.getattr(::pyo3::intern!(py, stringify!($callable)))?)+;
-                let kwargs = ::pyo3::types::PyDict::new(py);
-                $(
-                    if stringify!($arg).starts_with("r#") {
-                        kwargs.set_item(stringify!($arg).trim_start_matches("r#"), $arg)?;
-                    }
-                    else {
-                        kwargs.set_item(::pyo3::intern!(py, stringify!($arg)), $arg)?;
-                    }
-                )+
-                callable.call((), Some(kwargs))?;
+    // [deep import] Callable with arguments: `[mod.submod.**].**.callable() => fn(arg: type, /, *, ...)`
+    { $(#[$meta:meta])* [$module:expr]$(.$callable:ident)+() => $vis:vis fn $fn_name:ident($($args:tt)+) } => {
+        $crate::bind_python_callable! {
+            @inner args {
+                { $(#[$meta])* $vis fn $fn_name }
+                {}
+                { with_gil py }
+                { ::pyo3::types::PyModule::import(py, ::pyo3::intern!(py, stringify!($module)))?$(.getattr(::pyo3::intern!(py, stringify!($callable)))?)+ }
+                {}
+            } [] [] [] [] $($args)+
+        }
+    };
+    // [deep import] Callable with arguments: `[mod.submod.**].**.callable() => fn(arg: type, /, *, ...) -> Result<value>`
+    { $(#[$meta:meta])* [$module:expr]$(.$callable:ident)+() => $vis:vis fn $fn_name:ident($($args:tt)+) -> $(::pyo3::Py)?Result<$value:ty> } => {
+        $crate::bind_python_callable! {
+            @inner args {
+                { $(#[$meta])* $vis fn $fn_name }
+                {}
+                { with_gil py }
+                { ::pyo3::types::PyModule::import(py, ::pyo3::intern!(py, stringify!($module)))?$(.getattr(::pyo3::intern!(py, stringify!($callable)))?)+ }
+                { $value }
+            } [] [] [] [] $($args)+
+        }
+    };
+    // Everything below are inner matches that parse Python-style argument lists for the macros above,
+    // i.e. arguments before `/` are passed positionally and all other arguments by keyword.
+    // The context consists of `{ fn head } { leading params } { GIL } { callable lookup } { return type }`,
+    // where the GIL is either the name of the GIL arg or `with_gil py` to acquire it within the function
+    { @inner args $context:tt [$($param:ident: $param_type:ty,)*] $positional:tt [$($pending:tt)*] $marker:tt $arg:ident: $arg_type:ty = None $(, $($rest:tt)*)? } => {
+        $crate::bind_python_callable! { @inner args $context [$($param: $param_type,)* $arg: $arg_type,] $positional [$($pending)* [$arg = None]] $marker $($($rest)*)? }
+    };
+    { @inner args $context:tt [$($param:ident: $param_type:ty,)*] $positional:tt [$($pending:tt)*] $marker:tt $arg:ident: $arg_type:ty $(, $($rest:tt)*)? } => {
+        $crate::bind_python_callable! { @inner args $context [$($param: $param_type,)* $arg: $arg_type,] $positional [$($pending)* [$arg]] $marker $($($rest)*)? }
+    };
+    { @inner args $context:tt $params:tt [] [$([$pending:ident])+] [] / $(, $($rest:tt)*)? } => {
+        $crate::bind_python_callable! { @inner args $context $params [$([$pending])+] [] [/] $($($rest)*)? }
+    };
+    { @inner args $context:tt $params:tt $positional:tt [] [] / $($rest:tt)* } => {
+        ::std::compile_error!("`/` must follow at least one positional-only argument");
+    };
+    { @inner args $context:tt $params:tt $positional:tt $pending:tt [/] / $($rest:tt)* } => {
+        ::std::compile_error!("`/` can appear only once in the arguments");
+    };
+    { @inner args $context:tt $params:tt $positional:tt $pending:tt [*] / $($rest:tt)* } => {
+        ::std::compile_error!("`/` must come before `*` in the arguments");
+    };
+    { @inner args $context:tt $params:tt $positional:tt $pending:tt [] / $($rest:tt)* } => {
+        ::std::compile_error!("arguments passed positionally cannot be omitted with `= None`");
+    };
+    { @inner args $context:tt [$($param:ident: $param_type:ty,)*] $positional:tt [$($pending:tt)*] $marker:tt * * $kwargs:ident: $kwargs_type:ty $(,)? } => {
+        $crate::bind_python_callable! { @inner args $context [$($param: $param_type,)* $kwargs: $kwargs_type,] $positional [$($pending)* [** $kwargs]] $marker }
+    };
+    { @inner args $context:tt $params:tt $positional:tt $pending:tt $marker:tt * * $($rest:tt)* } => {
+        ::std::compile_error!("`**kwargs` must be the last argument");
+    };
+    { @inner args $context:tt $params:tt $positional:tt $pending:tt [$(/)?] *, $($rest:tt)+ } => {
+        $crate::bind_python_callable! { @inner args $context $params $positional $pending [*] $($rest)+ }
+    };
+    { @inner args $context:tt [$($param:ident: $param_type:ty,)*] [$($positional:tt)*] [$([$pending:ident])*] [$(/)?] * $args:ident: $args_type:ty $(, $($rest:tt)*)? } => {
+        $crate::bind_python_callable! { @inner args $context [$($param: $param_type,)* $args: $args_type,] [$($positional)* $([$pending])* [* $args]] [] [*] $($($rest)*)? }
+    };
+    { @inner args $context:tt $params:tt $positional:tt $pending:tt [$(/)?] * $args:ident: $($rest:tt)* } => {
+        ::std::compile_error!("arguments passed positionally cannot be omitted with `= None`");
+    };
+    { @inner args $context:tt $params:tt $positional:tt $pending:tt [*] * $($rest:tt)* } => {
+        ::std::compile_error!("`*` or `*args` can appear only once in the arguments");
+    };
+    { @inner args $context:tt $params:tt $positional:tt $pending:tt $marker:tt * $(,)? } => {
+        ::std::compile_error!("`*` must be followed by at least one keyword-only argument");
+    };
+    { @inner args { $($context:tt)* } $params:tt $positional:tt $keyword:tt $marker:tt } => {
+        $crate::bind_python_callable! { @inner fn $($context)* $params $positional $keyword }
+    };
+    { @inner fn { $($head:tt)* } { $($lead:tt)* } { $gil:ident } $lookup:tt {} [$($param:ident: $param_type:ty,)*] $positional:tt $keyword:tt } => {
+        $($head)*($($lead)* $($param: $param_type),*) -> ::pyo3::PyResult<()> {
+            $crate::bind_python_callable!(@inner call $gil $lookup $positional $keyword)?;
+            Ok(())
+        }
+    };
+    { @inner fn { $($head:tt)* } { $($lead:tt)* } { $gil:ident } $lookup:tt { $value:ty } [$($param:ident: $param_type:ty,)*] $positional:tt $keyword:tt } => {
+        $($head)*($($lead)* $($param: $param_type),*) -> ::pyo3::PyResult<$value> {
+            $crate::bind_python_callable!(@inner call $gil $lookup $positional $keyword)?.extract()
+        }
+    };
+    { @inner fn { $($head:tt)* } { $($lead:tt)* } { with_gil $gil:ident } $lookup:tt {} [$($param:ident: $param_type:ty,)*] $positional:tt $keyword:tt } => {
+        $($head)*($($lead)* $($param: $param_type),*) -> ::pyo3::PyResult<()> {
+            ::pyo3::Python::with_gil(|$gil| {
+                $crate::bind_python_callable!(@inner call $gil $lookup $positional $keyword)?;
                 Ok(())
             })
         }
     };
-    // [deep import] Callable with keyword arguments: `[mod.submod.**].**.callable() => fn(arg: type, ...) -> Result<value>`
-    { $(#[$meta:meta])* [$module:expr]$(.$callable:ident)+() => $vis:vis fn $fn_name:ident($($arg:ident: $arg_type:ty),*) -> $(::pyo3::Py)?Result<$value:ty> } => {
-        $(#[$meta])*
-        $vis fn $fn_name($($arg: $arg_type),+) -> ::pyo3::PyResult<$value> {
-            ::pyo3::Python::with_gil(|py| {
-                let module: &::pyo3::PyAny = ::pyo3::types::PyModule::import(py, ::pyo3::intern!(py, stringify!($module)))?;
-                let callable = module$(.getattr(::pyo3::intern!(py, stringify!($callable)))?)+;
-                let kwargs = ::pyo3::types::PyDict::new(py);
-                $(
-                    if stringify!($arg).starts_with("r#") {
-                        kwargs.set_item(stringify!($arg).trim_start_matches("r#"), $arg)?;
-                    }
-                    else {
-                        kwargs.set_item(::pyo3::intern!(py, stringify!($arg)), $arg)?;
-                    }
-                )+
-                callable.call((), Some(kwargs))?.extract()
+    { @inner fn { $($head:tt)* } { $($lead:tt)* } { with_gil $gil:ident } $lookup:tt { $value:ty } [$($param:ident: $param_type:ty,)*] $positional:tt $keyword:tt } => {
+        $($head)*($($lead)* $($param: $param_type),*) -> ::pyo3::PyResult<$value> {
+            ::pyo3::Python::with_gil(|$gil| {
+                $crate::bind_python_callable!(@inner call $gil $lookup $positional $keyword)?.extract()
             })
         }
     };
+    { @inner call $gil:ident { $($lookup:tt)* } [$([$($positional:tt)+])*] [$([$($keyword:tt)+])*] } => {{
+        let callable = $($lookup)*;
+        let args: ::std::vec::Vec<::pyo3::PyObject> = ::std::iter::empty()
+            $(.chain($crate::bind_python_callable!(@inner arg $gil $($positional)+)))*
+            .collect();
+        let kwargs = ::pyo3::types::PyDict::new($gil);
+        $(
+            $crate::bind_python_callable! { @inner kwarg $gil kwargs $($keyword)+ }
+        )*
+        callable.call(::pyo3::types::PyTuple::new($gil, args), Some(kwargs))
+    }};
+    { @inner arg $gil:ident * $args:ident } => {
+        ::pyo3::IntoPy::<::pyo3::Py<::pyo3::types::PyTuple>>::into_py($args, $gil)
+            .into_ref($gil)
+            .iter()
+            .map(::std::convert::Into::into)
+    };
+    { @inner arg $gil:ident $arg:ident } => {
+        ::std::iter::once(::pyo3::ToPyObject::to_object(&$arg, $gil))
+    };
+    { @inner kwarg $gil:ident $kwargs:ident ** $extra:ident } => {
+        for (key, value) in $crate::TryIntoPyDict::try_into_py_dict($extra, $gil)? {
+            if $kwargs.contains(key)? {
+                return Err(::pyo3::exceptions::PyTypeError::new_err(format!(
+                    "got multiple values for keyword argument '{}'",
+                    key
+                )));
+            }
+            $kwargs.set_item(key, value)?;
+        }
+    };
+    { @inner kwarg $gil:ident $kwargs:ident $arg:ident = None } => {
+        if let Some($arg) = $arg {
+            $crate::bind_python_callable! { @inner kwarg $gil $kwargs $arg }
+        }
+    };
+    { @inner kwarg $gil:ident $kwargs:ident $arg:ident } => {
+        if stringify!($arg).starts_with("r#") {
+            $kwargs.set_item(stringify!($arg).trim_start_matches("r#"), $arg)?;
+        }
+        else {
+            $kwargs.set_item(::pyo3::intern!($gil, stringify!($arg)), $arg)?;
+        }
+    };
+    // Python object that is resolved once and then reused until `invalidate_cached_bindings()` is called
+    { @inner cached $gil:ident { $($resolve:tt)* } } => {{
+        static CACHE: ::pyo3::sync::GILProtected<::std::cell::RefCell<::std::option::Option<(usize, ::pyo3::PyObject)>>> =
+            ::pyo3::sync::GILProtected::new(::std::cell::RefCell::new(None));
+        let generation = $crate::cached_bindings_generation();
+        let cached = CACHE
+            .get($gil)
+            .borrow()
+            .as_ref()
+            .filter(|(cached_generation, _)| *cached_generation == generation)
+            .map(|(_, object)| object.clone_ref($gil));
+        match cached {
+            Some(object) => object.into_ref($gil),
+            None => {
+                let object: &::pyo3::PyAny = $($resolve)*;
+                *CACHE.get($gil).borrow_mut() = Some((generation, object.into()));
+                object
+            }
+        }
+    }};
 }
 
 /// Bind a Python callable of `self` to a Rust function.
+///
+/// The arguments support the same `/` and `*` markers, `= None` defaults and `*args`/`**kwargs`
+/// passthrough as [`bind_python_callable!`].
 #[macro_export]
 macro_rules! bind_python_self_callable {
     // [with GIL arg, &mut self] Callable without arguments: `mod.**.callable(py: Python) => fn()`
@@ -192,39 +353,28 @@ macro_rules! bind_python_self_callable {
             callable.call0()?.extract()
         }
     };
-    // [with GIL arg, &mut self] Callable with keyword arguments: `mod.**.callable() => fn(arg: type, ...)
-    { $(#[$meta:meta])* $module:ident$(.$callable:ident)+() => $vis:vis fn $fn_name:ident$(<$lf_fn:lifetime>)?(&$($lf_self:lifetime)?mut self, $gil:ident: $(::pyo3::)?Python$(<$lf_python:lifetime>)?$(, $arg:ident: $arg_type:ty)+) } => {
-        $(#[$meta])*
-        $vis fn $fn_name$(<$lf_fn>)?(&$($lf_self)?mut self, $gil: ::pyo3::Python$(<$lf_python>)?$(, $arg: $arg_type)*) -> ::pyo3::PyResult<()> {
-            let callable = self.as_ref($gil)$(.getattr(::pyo3::intern!($gil, stringify!($callable)))?)+;
-            let kwargs = ::pyo3::types::PyDict::new($gil);
-            $(
-                if stringify!($arg).starts_with("r#") {
-                    kwargs.set_item(stringify!($arg).trim_start_matches("r#"), $arg)?;
-                }
-                else {
-                    kwargs.set_item(::pyo3::intern!($gil, stringify!($arg)), $arg)?;
-                }
-            )+
-            callable.call((), Some(kwargs))?;
-            Ok(())
+    // [with GIL arg, &mut self] Callable with arguments: `mod.**.callable() => fn(py: Python, arg: type, /, *, ...)`
+    { $(#[$meta:meta])* $module:ident$(.$callable:ident)+() => $vis:vis fn $fn_name:ident$(<$lf_fn:lifetime>)?(&$($lf_self:lifetime)?mut self, $gil:ident: $(::pyo3::)?Python$(<$lf_python:lifetime>)?, $($args:tt)+) } => {
+        $crate::bind_python_callable! {
+            @inner args {
+                { $(#[$meta])* $vis fn $fn_name$(<$lf_fn>)? }
+                { &$($lf_self)?mut self, $gil: ::pyo3::Python$(<$lf_python>)?, }
+                { $gil }
+                { self.as_ref($gil)$(.getattr(::pyo3::intern!($gil, stringify!($callable)))?)+ }
+                {}
+            } [] [] [] [] $($args)+
         }
     };
-    // [with GIL arg, &mut self] Callable with keyword arguments: `mod.**.callable() => fn(arg: type, ...) -> Result<value>`
-    { $(#[$meta:meta])* $module:ident$(.$callable:ident)+() => $vis:vis fn $fn_name:ident$(<$lf_fn:lifetime>)?(&$($lf_self:lifetime)?mut self, $gil:ident: $(::pyo3::)?Python$(<$lf_python:lifetime>)?$(, $arg:ident: $arg_type:ty)+) -> $(::pyo3::Py)?Result<$value:ty> } => {
-        $(#[$meta])*
-        $vis fn $fn_name$(<$lf_fn>)?(&$($lf_self)?mut self, $gil: ::pyo3::Python$(<$lf_python>)?$(, $arg: $arg_type)*) -> ::pyo3::PyResult<$value> {
-            let callable = self.as_ref($gil)$(.getattr(::pyo3::intern!($gil, stringify!($callable)))?)+;
-            let kwargs = ::pyo3::types::PyDict::new($gil);
-            $(
-                if stringify!($arg).starts_with("r#") {
-                    kwargs.set_item(stringify!($arg).trim_start_matches("r#"), $arg)?;
-                }
-                else {
-                    kwargs.set_item(::pyo3::intern!($gil, stringify!($arg)), $arg)?;
-                }
-            )+
-            callable.call((), Some(kwargs))?.extract()
+    // [with GIL arg, &mut self] Callable with arguments: `mod.**.callable() => fn(py: Python, arg: type, /, *, ...) -> Result<value>`
+    { $(#[$meta:meta])* $module:ident$(.$callable:ident)+() => $vis:vis fn $fn_name:ident$(<$lf_fn:lifetime>)?(&$($lf_self:lifetime)?mut self, $gil:ident: $(::pyo3::)?Python$(<$lf_python:lifetime>)?, $($args:tt)+) -> $(::pyo3::Py)?Result<$value:ty> } => {
+        $crate::bind_python_callable! {
+            @inner args {
+                { $(#[$meta])* $vis fn $fn_name$(<$lf_fn>)? }
+                { &$($lf_self)?mut self, $gil: ::pyo3::Python$(<$lf_python>)?, }
+                { $gil }
+                { self.as_ref($gil)$(.getattr(::pyo3::intern!($gil, stringify!($callable)))?)+ }
+                { $value }
+            } [] [] [] [] $($args)+
         }
     };
     // [&mut self] Callable without arguments: `mod.**.callable() => fn()`
@@ -248,43 +398,28 @@ macro_rules! bind_python_self_callable {
             })
         }
     };
-    // [&mut self] Callable with keyword arguments: `mod.**.callable() => fn(arg: type, ...)`
-    { $(#[$meta:meta])* $module:ident$(.$callable:ident)+() => $vis:vis fn $fn_name:ident(&mut self, $($arg:ident: $arg_type:ty),*) } => {
-        $(#[$meta])*
-        $vis fn $fn_name(&mut self, $($arg: $arg_type),+) -> ::pyo3::PyResult<()> {
-            ::pyo3::Python::with_gil(|py| {
-                let callable = self.as_ref(py)$(.getattr(::pyo3::intern!(py, stringify!($callable)))?)+;
-                let kwargs = ::pyo3::types::PyDict::new(py);
-                $(
-                    if stringify!($arg).starts_with("r#") {
-                        kwargs.set_item(stringify!($arg).trim_start_matches("r#"), $arg)?;
-                    }
-                    else {
-                        kwargs.set_item(::pyo3::intern!(py, stringify!($arg)), $arg)?;
-                    }
-                )+
-                callable.call((), Some(kwargs))?;
-                Ok(())
-            })
+    // [&mut self] Callable with arguments: `mod.**.callable() => fn(arg: type, /, *, ...)`
+    { $(#[$meta:meta])* $module:ident$(.$callable:ident)+() => $vis:vis fn $fn_name:ident(&mut self, $($args:tt)+) } => {
+        $crate::bind_python_callable! {
+            @inner args {
+                { $(#[$meta])* $vis fn $fn_name }
+                { &mut self, }
+                { with_gil py }
+                { self.as_ref(py)$(.getattr(::pyo3::intern!(py, stringify!($callable)))?)+ }
+                {}
+            } [] [] [] [] $($args)+
         }
     };
-    // [&mut self] Callable with keyword arguments: `mod.**.callable() => fn(arg: type, ...) -> Result<value>`
-    { $(#[$meta:meta])* $module:ident$(.$callable:ident)+() => $vis:vis fn $fn_name:ident(&mut self, $($arg:ident: $arg_type:ty),*) -> $(::pyo3::Py)?Result<$value:ty> } => {
-        $(#[$meta])*
-        $vis fn $fn_name(&mut self, $($arg: $arg_type),+) -> ::pyo3::PyResult<$value> {
-            ::pyo3::Python::with_gil(|py| {
-                let callable = self.as_ref(py)$(.getattr(::pyo3::intern!(py, stringify!($callable)))?)+;
-                let kwargs = ::pyo3::types::PyDict::new(py);
-                $(
-                    if stringify!($arg).starts_with("r#") {
-                        kwargs.set_item(stringify!($arg).trim_start_matches("r#"), $arg)?;
-                    }
-                    else {
-                        kwargs.set_item(::pyo3::intern!(py, stringify!($arg)), $arg)?;
-                    }
-                )+
-                callable.call((), Some(kwargs))?.extract()
-            })
+    // [&mut self] Callable with arguments: `mod.**.callable() => fn(arg: type, /, *, ...) -> Result<value>`
+    { $(#[$meta:meta])* $module:ident$(.$callable:ident)+() => $vis:vis fn $fn_name:ident(&mut self, $($args:tt)+) -> $(::pyo3::Py)?Result<$value:ty> } => {
+        $crate::bind_python_callable! {
+            @inner args {
+                { $(#[$meta])* $vis fn $fn_name }
+                { &mut self, }
+                { with_gil py }
+                { self.as_ref(py)$(.getattr(::pyo3::intern!(py, stringify!($callable)))?)+ }
+                { $value }
+            } [] [] [] [] $($args)+
         }
     };
     // [with GIL arg] Callable without arguments: `mod.**.callable(py: Python) => fn()`
@@ -304,39 +439,28 @@ macro_rules! bind_python_self_callable {
             callable.call0()?.extract()
         }
     };
-    // [with GIL arg] Callable with keyword arguments: `mod.**.callable() => fn(arg: type, ...)
-    { $(#[$meta:meta])* $module:ident$(.$callable:ident)+() => $vis:vis fn $fn_name:ident$(<$lf_fn:lifetime>)?(&$($lf_self:lifetime)?self, $gil:ident: $(::pyo3::)?Python$(<$lf_python:lifetime>)?$(, $arg:ident: $arg_type:ty)+) } => {
-        $(#[$meta])*
-        $vis fn $fn_name$(<$lf_fn>)?(&$($lf_self)?self, $gil: ::pyo3::Python$(<$lf_python>)?$(, $arg: $arg_type)*) -> ::pyo3::PyResult<()> {
-            let callable = self.as_ref($gil)$(.getattr(::pyo3::intern!($gil, stringify!($callable)))?)+;
-            let kwargs = ::pyo3::types::PyDict::new($gil);
-            $(
-                if stringify!($arg).starts_with("r#") {
-                    kwargs.set_item(stringify!($arg).trim_start_matches("r#"), $arg)?;
-                }
-                else {
-                    kwargs.set_item(::pyo3::intern!($gil, stringify!($arg)), $arg)?;
-                }
-            )+
-            callable.call((), Some(kwargs))?;
-            Ok(())
+    // [with GIL arg] Callable with arguments: `mod.**.callable() => fn(py: Python, arg: type, /, *, ...)`
+    { $(#[$meta:meta])* $module:ident$(.$callable:ident)+() => $vis:vis fn $fn_name:ident$(<$lf_fn:lifetime>)?(&$($lf_self:lifetime)?self, $gil:ident: $(::pyo3::)?Python$(<$lf_python:lifetime>)?, $($args:tt)+) } => {
+        $crate::bind_python_callable! {
+            @inner args {
+                { $(#[$meta])* $vis fn $fn_name$(<$lf_fn>)? }
+                { &$($lf_self)?self, $gil: ::pyo3::Python$(<$lf_python>)?, }
+                { $gil }
+                { self.as_ref($gil)$(.getattr(::pyo3::intern!($gil, stringify!($callable)))?)+ }
+                {}
+            } [] [] [] [] $($args)+
         }
     };
-    // [with GIL arg] Callable with keyword arguments: `mod.**.callable() => fn(arg: type, ...) -> Result<value>`
-    { $(#[$meta:meta])* $module:ident$(.$callable:ident)+() => $vis:vis fn $fn_name:ident$(<$lf_fn:lifetime>)?(&$($lf_self:lifetime)?self, $gil:ident: $(::pyo3::)?Python$(<$lf_python:lifetime>)?$(, $arg:ident: $arg_type:ty)+) -> $(::pyo3::Py)?Result<$value:ty> } => {
-        $(#[$meta])*
-        $vis fn $fn_name$(<$lf_fn>)?(&$($lf_self)?self, $gil: ::pyo3::Python$(<$lf_python>)?$(, $arg: $arg_type)*) -> ::pyo3::PyResult<$value> {
-            let callable = self.as_ref($gil)$(.getattr(::pyo3::intern!($gil, stringify!($callable)))?)+;
-            let kwargs = ::pyo3::types::PyDict::new($gil);
-            $(
-                if stringify!($arg).starts_with("r#") {
-                    kwargs.set_item(stringify!($arg).trim_start_matches("r#"), $arg)?;
-                }
-                else {
-                    kwargs.set_item(::pyo3::intern!($gil, stringify!($arg)), $arg)?;
-                }
-            )+
-            callable.call((), Some(kwargs))?.extract()
+    // [with GIL arg] Callable with arguments: `mod.**.callable() => fn(py: Python, arg: type, /, *, ...) -> Result<value>`
+    { $(#[$meta:meta])* $module:ident$(.$callable:ident)+() => $vis:vis fn $fn_name:ident$(<$lf_fn:lifetime>)?(&$($lf_self:lifetime)?self, $gil:ident: $(::pyo3::)?Python$(<$lf_python:lifetime>)?, $($args:tt)+) -> $(::pyo3::Py)?Result<$value:ty> } => {
+        $crate::bind_python_callable! {
+            @inner args {
+                { $(#[$meta])* $vis fn $fn_name$(<$lf_fn>)? }
+                { &$($lf_self)?self, $gil: ::pyo3::Python$(<$lf_python>)?, }
+                { $gil }
+                { self.as_ref($gil)$(.getattr(::pyo3::intern!($gil, stringify!($callable)))?)+ }
+                { $value }
+            } [] [] [] [] $($args)+
         }
     };
     // Callable without arguments: `mod.**.callable() => fn()`
@@ -360,43 +484,28 @@ macro_rules! bind_python_self_callable {
             })
         }
     };
-    // Callable with keyword arguments: `mod.**.callable() => fn(arg: type, ...)`
-    { $(#[$meta:meta])* $module:ident$(.$callable:ident)+() => $vis:vis fn $fn_name:ident(&self, $($arg:ident: $arg_type:ty),*) } => {
-        $(#[$meta])*
-        $vis fn $fn_name(&self, $($arg: $arg_type),+) -> ::pyo3::PyResult<()> {
-            ::pyo3::Python::with_gil(|py| {
-                let callable = self.as_ref(py)$(.getattr(::pyo3::intern!(py, stringify!($callable)))?)+;
-                let kwargs = ::pyo3::types::PyDict::new(py);
-                $(
-                    if stringify!($arg).starts_with("r#") {
-                        kwargs.set_item(stringify!($arg).trim_start_matches("r#"), $arg)?;
-                    }
-                    else {
-                        kwargs.set_item(::pyo3::intern!(py, stringify!($arg)), $arg)?;
-                    }
-                )+
-                callable.call((), Some(kwargs))?;
-                Ok(())
-            })
+    // Callable with arguments: `mod.**.callable() => fn(arg: type, /, *, ...)`
+    { $(#[$meta:meta])* $module:ident$(.$callable:ident)+() => $vis:vis fn $fn_name:ident(&self, $($args:tt)+) } => {
+        $crate::bind_python_callable! {
+            @inner args {
+                { $(#[$meta])* $vis fn $fn_name }
+                { &self, }
+                { with_gil py }
+                { self.as_ref(py)$(.getattr(::pyo3::intern!(py, stringify!($callable)))?)+ }
+                {}
+            } [] [] [] [] $($args)+
         }
     };
-    // Callable with keyword arguments: `mod.**.callable() => fn(arg: type, ...) -> Result<value>`
-    { $(#[$meta:meta])* $module:ident$(.$callable:ident)+() => $vis:vis fn $fn_name:ident(&self, $($arg:ident: $arg_type:ty),*) -> $(::pyo3::Py)?Result<$value:ty> } => {
-        $(#[$meta])*
-        $vis fn $fn_name(&self, $($arg: $arg_type),+) -> ::pyo3::PyResult<$value> {
-            ::pyo3::Python::with_gil(|py| {
-                let callable = self.as_ref(py)$(.getattr(::pyo3::intern!(py, stringify!($callable)))?)+;
-                let kwargs = ::pyo3::types::PyDict::new(py);
-                $(
-                    if stringify!($arg).starts_with("r#") {
-                        kwargs.set_item(stringify!($arg).trim_start_matches("r#"), $arg)?;
-                    }
-                    else {
-                        kwargs.set_item(::pyo3::intern!(py, stringify!($arg)), $arg)?;
-                    }
-                )+
-                callable.call((), Some(kwargs))?.extract()
-            })
+    // Callable with arguments: `mod.**.callable() => fn(arg: type, /, *, ...) -> Result<value>`
+    { $(#[$meta:meta])* $module:ident$(.$callable:ident)+() => $vis:vis fn $fn_name:ident(&self, $($args:tt)+) -> $(::pyo3::Py)?Result<$value:ty> } => {
+        $crate::bind_python_callable! {
+            @inner args {
+                { $(#[$meta])* $vis fn $fn_name }
+                { &self, }
+                { with_gil py }
+                { self.as_ref(py)$(.getattr(::pyo3::intern!(py, stringify!($callable)))?)+ }
+                { $value }
+            } [] [] [] [] $($args)+
         }
     };
 }
@@ -428,8 +537,19 @@ macro_rules! test_bind_python_getter {
 }
 
 /// Bind a Python getter to a Rust function.
+///
+/// With `static` before the path of the attribute, the object that owns the attribute is resolved
+/// only once as with [`bind_python_callable!`], while the attribute itself is read on every call.
 #[macro_export]
 macro_rules! bind_python_getter {
+    // [static] Getter with an owner that is resolved only once: `static mod.**.attr => fn(...) -> Result<value>`
+    { $(#[$meta:meta])* static $module:ident$(.$attr:ident)+ => $($macro_tail:tt)+ } => {
+        $crate::bind_python_getter! { @inner static $(#[$meta])* [$module] [] $(.$attr)+ => $($macro_tail)+ }
+    };
+    // [static, deep import] Getter with an owner that is resolved only once: `static [mod.submod.**].**.attr => fn(...) -> Result<value>`
+    { $(#[$meta:meta])* static [$module:expr]$(.$attr:ident)+ => $($macro_tail:tt)+ } => {
+        $crate::bind_python_getter! { @inner static $(#[$meta])* [$module] [] $(.$attr)+ => $($macro_tail)+ }
+    };
     // [with GIL arg] Getter `mod.**.attr => fn(py: ::pyo3::Python) -> Result<value>`
     { $(#[$meta:meta])* $module:ident$(.$attr:ident)+ => $vis:vis fn $fn_name:ident$(<$lf_fn:lifetime>)?($gil:ident: $(::pyo3::)?Python$(<$lf_python:lifetime>)?) -> $(::pyo3::Py)?Result<$value:ty> } => {
         $crate::bind_python_getter! {
@@ -464,6 +584,26 @@ macro_rules! bind_python_getter {
             })
         }
     };
+    // Everything below are inner matches that split the owner of the attribute for the `static` getters above
+    { @inner static $(#[$meta:meta])* [$module:expr] [$($owner:ident)*] .$attr:ident .$next:ident$(.$rest:ident)* => $($macro_tail:tt)+ } => {
+        $crate::bind_python_getter! { @inner static $(#[$meta])* [$module] [$($owner)* $attr] .$next$(.$rest)* => $($macro_tail)+ }
+    };
+    { @inner static $(#[$meta:meta])* [$module:expr] [$($owner:ident)*] .$attr:ident => $vis:vis fn $fn_name:ident$(<$lf_fn:lifetime>)?($gil:ident: $(::pyo3::)?Python$(<$lf_python:lifetime>)?) -> $(::pyo3::Py)?Result<$value:ty> } => {
+        $(#[$meta])*
+        $vis fn $fn_name$(<$lf_fn>)?($gil: ::pyo3::Python$(<$lf_python>)?) -> ::pyo3::PyResult<$value> {
+            let owner = $crate::bind_python_callable!(@inner cached $gil { ::pyo3::types::PyModule::import($gil, ::pyo3::intern!($gil, stringify!($module)))?$(.getattr(::pyo3::intern!($gil, stringify!($owner)))?)* });
+            owner.getattr(::pyo3::intern!($gil, stringify!($attr)))?.extract()
+        }
+    };
+    { @inner static $(#[$meta:meta])* [$module:expr] [$($owner:ident)*] .$attr:ident => $vis:vis fn $fn_name:ident() -> $(::pyo3::Py)?Result<$value:ty> } => {
+        $(#[$meta])*
+        $vis fn $fn_name() -> ::pyo3::PyResult<$value> {
+            ::pyo3::Python::with_gil(|py| {
+                let owner = $crate::bind_python_callable!(@inner cached py { ::pyo3::types::PyModule::import(py, ::pyo3::intern!(py, stringify!($module)))?$(.getattr(::pyo3::intern!(py, stringify!($owner)))?)* });
+                owner.getattr(::pyo3::intern!(py, stringify!($attr)))?.extract()
+            })
+        }
+    };
 }
 
 /// Bind a Python getter of `self` to a Rust function.
@@ -478,7 +618,7 @@ macro_rules! bind_python_self_getter {
         }
     };
     // Self getter `self.**.attr => fn(&self) -> Result<value>`
-    { $(#[$meta:meta])* self$(.$attr:ident)+ => $vis:vis fn $fn_name:ident(&self) -> $(::pyo3::Py)?Result<$value:ty> } => {
+    { $(#[$meta:meta])* self$(.$attr:ident)+ => $vis:vis fn $fn_name:ident$(<$lf_fn:lifetime>)?(&$($lf_self:lifetime)?self) -> $(::pyo3::Py)?Result<$value:ty> } => {
         $(#[$meta])*
         $vis fn $fn_name$(<$lf_fn>)?(&$($lf_self)?self) -> ::pyo3::PyResult<$value> {
             ::pyo3::Python::with_gil(|py| {
@@ -490,11 +630,22 @@ macro_rules! bind_python_self_getter {
 }
 
 /// Bind a Python setter to a Rust function.
+///
+/// With `static` before the path of the attribute, the object that owns the attribute is resolved
+/// only once as with [`bind_python_callable!`], while the attribute itself is set on every call.
 #[macro_export]
 macro_rules! bind_python_setter {
+    // [static] Setter with an owner that is resolved only once: `static mod.**.attr = fn(...)`
+    { $(#[$meta:meta])* static $module:ident$(.$attr:ident)+ = $($macro_tail:tt)+ } => {
+        $crate::bind_python_setter! { @inner static $(#[$meta])* [$module] [] $(.$attr)+ = $($macro_tail)+ }
+    };
+    // [static, deep import] Setter with an owner that is resolved only once: `static [mod.submod.**].**.attr = fn(...)`
+    { $(#[$meta:meta])* static [$module:expr]$(.$attr:ident)+ = $($macro_tail:tt)+ } => {
+        $crate::bind_python_setter! { @inner static $(#[$meta])* [$module] [] $(.$attr)+ = $($macro_tail)+ }
+    };
     // Setter: `mod.attr = fn(py: Python, value: type)`
     { $(#[$meta:meta])* $module:ident.$attr:ident = $($macro_tail:tt)+ } => {
-        $crate::bind_python_self_setter! { @inner end $(#[$meta])* [$module] $attr = $($macro_tail)+ }
+        $crate::bind_python_setter! { @inner end $(#[$meta])* [$module] $attr = $($macro_tail)+ }
     };
     // [with GIL arg] Setter: `mod.**.attr = fn(py: Python, value: type)`
     { $(#[$meta:meta])* $module:ident$(.$attr:ident)+ = $vis:vis fn $fn_name:ident$(<$lf_fn:lifetime>)?($gil:ident: $(::pyo3::)?Python$(<$lf_python:lifetime>)?, $value:ident: $value_type:ty) } => {
@@ -551,6 +702,25 @@ macro_rules! bind_python_setter {
             })
         }
     };
+    { @inner static $(#[$meta:meta])* [$module:expr] [$($owner:ident)*] .$attr:ident .$next:ident$(.$rest:ident)* = $($macro_tail:tt)+ } => {
+        $crate::bind_python_setter! { @inner static $(#[$meta])* [$module] [$($owner)* $attr] .$next$(.$rest)* = $($macro_tail)+ }
+    };
+    { @inner static $(#[$meta:meta])* [$module:expr] [$($owner:ident)*] .$attr:ident = $vis:vis fn $fn_name:ident$(<$lf_fn:lifetime>)?($gil:ident: $(::pyo3::)?Python$(<$lf_python:lifetime>)?, $value:ident: $value_type:ty) } => {
+        $(#[$meta])*
+        $vis fn $fn_name$(<$lf_fn>)?($gil: ::pyo3::Python$(<$lf_python>)?, $value: $value_type) -> ::pyo3::PyResult<()> {
+            let owner = $crate::bind_python_callable!(@inner cached $gil { ::pyo3::types::PyModule::import($gil, ::pyo3::intern!($gil, stringify!($module)))?$(.getattr(::pyo3::intern!($gil, stringify!($owner)))?)* });
+            owner.setattr(::pyo3::intern!($gil, stringify!($attr)), $value)
+        }
+    };
+    { @inner static $(#[$meta:meta])* [$module:expr] [$($owner:ident)*] .$attr:ident = $vis:vis fn $fn_name:ident($value:ident: $value_type:ty) } => {
+        $(#[$meta])*
+        $vis fn $fn_name($value: $value_type) -> ::pyo3::PyResult<()> {
+            ::pyo3::Python::with_gil(|py| {
+                let owner = $crate::bind_python_callable!(@inner cached py { ::pyo3::types::PyModule::import(py, ::pyo3::intern!(py, stringify!($module)))?$(.getattr(::pyo3::intern!(py, stringify!($owner)))?)* });
+                owner.setattr(::pyo3::intern!(py, stringify!($attr)), $value)
+            })
+        }
+    };
 }
 
 /// Bind a Python setter of `self` to a Rust function.
@@ -653,6 +823,48 @@ macro_rules! python_wrap_with_gil {
 /// It is a combination of [`bind_python_callable!`], [`bind_python_getter!`], [`bind_python_setter!`] and [`python_wrap_with_gil!`].
 #[macro_export]
 macro_rules! bind_python {
+    // [static] Callable
+    { $(#[$meta:meta])* static $module:ident$(.$callable:ident)+() => $($macro_tail:tt)+ } => {
+        $crate::bind_python_callable! {
+            $(#[$meta])*
+            static $module$(.$callable)+() => $($macro_tail)+
+        }
+    };
+    // [static, deep import] Callable
+    { $(#[$meta:meta])* static [$module:expr]$(.$callable:ident)+() => $($macro_tail:tt)+ } => {
+        $crate::bind_python_callable! {
+            $(#[$meta])*
+            static [$module]$(.$callable)+() => $($macro_tail)+
+        }
+    };
+    // [static] Getter
+    { $(#[$meta:meta])* static $module:ident$(.$attr:ident)+ => $($macro_tail:tt)+ } => {
+        $crate::bind_python_getter! {
+            $(#[$meta])*
+            static $module$(.$attr)+ => $($macro_tail)+
+        }
+    };
+    // [static, deep import] Getter
+    { $(#[$meta:meta])* static [$module:expr]$(.$attr:ident)+ => $($macro_tail:tt)+ } => {
+        $crate::bind_python_getter! {
+            $(#[$meta])*
+            static [$module]$(.$attr)+ => $($macro_tail)+
+        }
+    };
+    // [static] Setter
+    { $(#[$meta:meta])* static $module:ident$(.$attr:ident)+ = $($macro_tail:tt)+ } => {
+        $crate::bind_python_setter! {
+            $(#[$meta])*
+            static $module$(.$attr)+ = $($macro_tail)+
+        }
+    };
+    // [static, deep import] Setter
+    { $(#[$meta:meta])* static [$module:expr]$(.$attr:ident)+ = $($macro_tail:tt)+ } => {
+        $crate::bind_python_setter! {
+            $(#[$meta])*
+            static [$module]$(.$attr)+ = $($macro_tail)+
+        }
+    };
     // Self callable (must be before other callable)
     { $(#[$meta:meta])* self$(.$callable:ident)+() => $($macro_tail:tt)+  } => {
         $crate::bind_python_self_callable! {
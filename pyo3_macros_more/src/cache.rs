@@ -0,0 +1,18 @@
+//! Invalidation of the Python objects that are cached by `static` bindings.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Generation of the cached Python objects, which are resolved again once it changes
+static GENERATION: AtomicUsize = AtomicUsize::new(0);
+
+/// Invalidate the Python objects cached by all `static` bindings, so that they are resolved again
+/// on their next call, e.g. after a module is reloaded with `importlib.reload`.
+pub fn invalidate_cached_bindings() {
+    GENERATION.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Current generation of the cached Python objects (used by the generated code).
+#[doc(hidden)]
+pub fn cached_bindings_generation() -> usize {
+    GENERATION.load(Ordering::Relaxed)
+}
@@ -0,0 +1,81 @@
+//! Fallible conversions into `PyDict`, which are implemented by the dict derives of `pyo3_derive_more`.
+
+use pyo3::{types::PyDict, PyResult, Python, ToPyObject};
+
+/// Fallible counterpart of `pyo3::types::IntoPyDict`, which returns the error of an item that fails
+/// to convert instead of panicking.
+///
+/// It is implemented for the types that derive `pyo3_derive_more::IntoPyDict` and for iterables of
+/// key-value pairs, e.g. `HashMap<K, V>`, `BTreeMap<K, V>`, `Vec<(K, V)>` or `&PyDict`.
+pub trait TryIntoPyDict {
+    /// Convert into a `pyo3::types::PyDict`, returning an error if any of the items fails to convert.
+    fn try_into_py_dict(self, py: Python<'_>) -> PyResult<&PyDict>;
+}
+
+impl<I> TryIntoPyDict for I
+where
+    I: IntoIterator,
+    I::Item: PyDictItem,
+{
+    fn try_into_py_dict(self, py: Python<'_>) -> PyResult<&PyDict> {
+        let dict = PyDict::new(py);
+        for item in self {
+            dict.set_item(item.key(), item.value())?;
+        }
+        Ok(dict)
+    }
+}
+
+/// Fallible counterpart of `pyo3::types::IntoPyDict` that does not consume `self`.
+///
+/// It is implemented for the types that derive `pyo3_derive_more::ToPyDict` and for the types whose
+/// references are iterables of key-value pairs, e.g. `HashMap<K, V>`, `BTreeMap<K, V>` or `Vec<(K, V)>`.
+pub trait TryToPyDict {
+    /// Convert into a `pyo3::types::PyDict` without consuming `self`, returning an error if any of
+    /// the items fails to convert.
+    fn try_to_py_dict<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict>;
+}
+
+impl<T: ?Sized> TryToPyDict for T
+where
+    for<'a> &'a T: TryIntoPyDict,
+{
+    fn try_to_py_dict<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        self.try_into_py_dict(py)
+    }
+}
+
+/// Key-value pair of an iterable that is converted into a `PyDict`
+pub trait PyDictItem {
+    type Key: ToPyObject;
+    type Value: ToPyObject;
+
+    fn key(&self) -> &Self::Key;
+    fn value(&self) -> &Self::Value;
+}
+
+impl<K: ToPyObject, V: ToPyObject> PyDictItem for (K, V) {
+    type Key = K;
+    type Value = V;
+
+    fn key(&self) -> &K {
+        &self.0
+    }
+
+    fn value(&self) -> &V {
+        &self.1
+    }
+}
+
+impl<K: ToPyObject, V: ToPyObject> PyDictItem for &(K, V) {
+    type Key = K;
+    type Value = V;
+
+    fn key(&self) -> &K {
+        &self.0
+    }
+
+    fn value(&self) -> &V {
+        &self.1
+    }
+}
@@ -1,3 +1,10 @@
 //! More declarative macros for `PyO3`.
 
 mod bind_python;
+mod cache;
+mod dict;
+
+#[doc(hidden)]
+pub use cache::cached_bindings_generation;
+pub use cache::invalidate_cached_bindings;
+pub use dict::{TryIntoPyDict, TryToPyDict};
@@ -0,0 +1,165 @@
+// Python cannot be embedded in the interpreter of Miri
+#![cfg(not(miri))]
+
+use std::collections::{BTreeMap, HashMap};
+
+use pyo3::{prelude::*, types::PyDict};
+use pyo3_macros_more::bind_python;
+
+bind_python! { math.hypot() => fn hypot(x: f64, y: f64, /) -> Result<f64> }
+bind_python! { builtins.divmod() => fn divmod(a: i32, b: i32, /,) -> Result<(i32, i32)> }
+bind_python! { builtins.int() => fn int(x: &str, /, base: Option<i32> = None) -> Result<i64> }
+bind_python! { builtins.sorted() => fn sorted(py: Python, iterable: Vec<i32>, /, *, reverse: bool) -> Result<Vec<i32>> }
+bind_python! { builtins.divmod() => fn divmod_by_keyword(a: i32, b: i32) -> Result<(i32, i32)> }
+bind_python! { [os.path].join() => fn join(a: &str, b: &str, /) -> Result<String> }
+bind_python! { json.dumps() => fn dumps(py: Python, obj: Vec<i32>, *, indent: Option<i32> = None, separators: Option<(&str, &str)> = None,) -> Result<String> }
+
+bind_python! { builtins.max() => fn max(first: i32, *rest: impl IntoPy<Py<pyo3::types::PyTuple>>, key: Option<PyObject> = None) -> Result<i32> }
+bind_python! { builtins.dict() => fn dict(py: Python, a: i32, **extra: &PyDict) -> Result<BTreeMap<String, i32>> }
+bind_python! { builtins.dict() => fn dict_from_map(**items: HashMap<String, i32>) -> Result<BTreeMap<String, i32>> }
+bind_python! { json.dumps() => fn dumps_with(obj: Vec<i32>, **options: DumpsOptions) -> Result<String> }
+bind_python! { json.dumps() => fn dumps_failing(obj: Vec<i32>, **options: FailingOptions) -> Result<String> }
+
+bind_python! { math.pi => fn pi() -> Result<f64> }
+bind_python! { sys.maxsize => fn maxsize(py: Python) -> Result<i64> }
+bind_python! { [xml.etree.ElementTree].VERSION => fn element_tree_version() -> Result<String> }
+bind_python! { sys.answer = fn set_answer(value: i32) }
+bind_python! { sys.answer => fn answer() -> Result<i32> }
+
+#[derive(pyo3_derive_more::IntoPyDict)]
+struct DumpsOptions {
+    sort_keys: bool,
+    #[pyo3_more(skip_if = "Option::is_none")]
+    indent: Option<i32>,
+}
+
+#[derive(pyo3_derive_more::IntoPyDict)]
+struct FailingOptions {
+    #[pyo3_more(with = "failing")]
+    indent: i32,
+}
+
+mod failing {
+    use pyo3::prelude::*;
+
+    pub fn to_py(_: &i32, _: Python) -> PyResult<PyObject> {
+        Err(pyo3::exceptions::PyValueError::new_err("invalid indent"))
+    }
+}
+
+/// Python object that is bound through `self`
+struct Namespace(PyObject);
+
+impl std::ops::Deref for Namespace {
+    type Target = PyObject;
+
+    fn deref(&self) -> &PyObject {
+        &self.0
+    }
+}
+
+impl Namespace {
+    fn new() -> Self {
+        Python::with_gil(|py| {
+            let namespace = py
+                .eval(
+                    "__import__('types').SimpleNamespace(items={}, inner=__import__('types').SimpleNamespace(value=1))",
+                    None,
+                    None,
+                )
+                .unwrap();
+            Self(namespace.into())
+        })
+    }
+
+    bind_python! { self.items.get() => fn get(&self, key: &str, default: i32, /) -> Result<i32> }
+    bind_python! { self.items.update() => fn update(&mut self, py: Python, **items: Vec<(&str, i32)>) }
+    bind_python! { self.items.pop() => fn pop(&mut self, key: &str, /) -> Result<i32> }
+    bind_python! { self.items.clear() => fn clear(&mut self) }
+    bind_python! { self.inner.value => fn value(&self) -> Result<i32> }
+    bind_python! { self.inner.value = fn set_value(&mut self, value: i32) }
+}
+
+#[test]
+fn positional_and_keyword_arguments() {
+    assert_eq!(hypot(3.0, 4.0).unwrap(), 5.0);
+    assert_eq!(divmod(7, 2).unwrap(), (3, 1));
+    assert_eq!(join("a", "b").unwrap(), "a/b");
+    assert_eq!(int("11", None).unwrap(), 11);
+    assert_eq!(int("11", Some(2)).unwrap(), 3);
+    Python::with_gil(|py| {
+        assert_eq!(sorted(py, vec![1, 3, 2], true).unwrap(), [3, 2, 1]);
+
+        assert_eq!(dumps(py, vec![1, 2], None, None).unwrap(), "[1, 2]");
+        assert_eq!(
+            dumps(py, vec![1, 2], Some(1), Some((",", ":"))).unwrap(),
+            "[\n 1,\n 2\n]"
+        );
+    });
+
+    // `divmod` takes its arguments positionally, so passing them by keyword fails
+    let err = divmod_by_keyword(7, 2).unwrap_err();
+    Python::with_gil(|py| assert!(err.is_instance_of::<pyo3::exceptions::PyTypeError>(py)));
+}
+
+#[test]
+fn variadic_arguments() {
+    assert_eq!(max(1, (5, 3), None).unwrap(), 5);
+    let negate = Python::with_gil(|py| py.eval("lambda x: -x", None, None).unwrap().into());
+    assert_eq!(max(1, (5, 3), Some(negate)).unwrap(), 1);
+
+    Python::with_gil(|py| {
+        let extra = PyDict::new(py);
+        extra.set_item("b", 2).unwrap();
+        assert_eq!(
+            dict(py, 1, extra).unwrap(),
+            BTreeMap::from([("a".to_owned(), 1), ("b".to_owned(), 2)])
+        );
+
+        // Keys of `**kwargs` must not collide with the named arguments
+        extra.set_item("a", 3).unwrap();
+        let err = dict(py, 1, extra).unwrap_err();
+        assert!(err.is_instance_of::<pyo3::exceptions::PyTypeError>(py));
+    });
+
+    assert_eq!(
+        dict_from_map(HashMap::from([("x".to_owned(), 1)])).unwrap(),
+        BTreeMap::from([("x".to_owned(), 1)])
+    );
+
+    let options = DumpsOptions {
+        sort_keys: true,
+        indent: None,
+    };
+    assert_eq!(dumps_with(vec![1], options).unwrap(), "[1]");
+
+    // Items of `**kwargs` that fail to convert return their error instead of panicking
+    let err = dumps_failing(vec![1], FailingOptions { indent: 1 }).unwrap_err();
+    Python::with_gil(|py| assert!(err.is_instance_of::<pyo3::exceptions::PyValueError>(py)));
+}
+
+#[test]
+fn getters_and_setters() {
+    assert_eq!(pi().unwrap(), std::f64::consts::PI);
+    Python::with_gil(|py| assert_eq!(maxsize(py).unwrap(), i64::MAX));
+    assert!(!element_tree_version().unwrap().is_empty());
+
+    set_answer(42).unwrap();
+    assert_eq!(answer().unwrap(), 42);
+}
+
+#[test]
+fn self_bindings() {
+    let mut namespace = Namespace::new();
+    Python::with_gil(|py| namespace.update(py, vec![("a", 1), ("b", 2)])).unwrap();
+    assert_eq!(namespace.get("a", 0).unwrap(), 1);
+    assert_eq!(namespace.get("c", 7).unwrap(), 7);
+    assert_eq!(namespace.pop("b").unwrap(), 2);
+    assert!(namespace.pop("b").is_err());
+    namespace.clear().unwrap();
+    assert_eq!(namespace.get("a", 0).unwrap(), 0);
+
+    assert_eq!(namespace.value().unwrap(), 1);
+    namespace.set_value(2).unwrap();
+    assert_eq!(namespace.value().unwrap(), 2);
+}
@@ -0,0 +1,58 @@
+// Python cannot be embedded in the interpreter of Miri
+#![cfg(not(miri))]
+
+use pyo3::prelude::*;
+use pyo3_macros_more::{bind_python, invalidate_cached_bindings};
+
+bind_python! { static math.hypot() => fn hypot(x: f64, y: f64, /) -> Result<f64> }
+bind_python! { static [os.path].basename() => fn basename(py: Python, p: &str, /) -> Result<String> }
+bind_python! { static json.dumps() => fn dumps(obj: Vec<i32>, *, indent: Option<i32> = None) -> Result<String> }
+bind_python! { static sys.flags.optimize => fn optimize(py: Python) -> Result<i32> }
+bind_python! { static [xml.etree.ElementTree].VERSION => fn element_tree_version() -> Result<String> }
+bind_python! { static sys.cached_answer = fn set_answer(py: Python, value: i32) }
+bind_python! { sys.cached_answer => fn answer() -> Result<i32> }
+
+#[test]
+fn static_bindings() {
+    Python::with_gil(|py| {
+        assert_eq!(basename(py, "/a/b").unwrap(), "b");
+        assert_eq!(basename(py, "/a/c").unwrap(), "c");
+        assert!(optimize(py).unwrap() >= 0);
+    });
+    assert_eq!(dumps(vec![1], None).unwrap(), "[1]");
+    assert_eq!(dumps(vec![1], Some(0)).unwrap(), "[\n1\n]");
+    assert!(!element_tree_version().unwrap().is_empty());
+
+    // The owner of a static getter and setter is cached, while the attribute is accessed on every call
+    Python::with_gil(|py| set_answer(py, 1)).unwrap();
+    assert_eq!(answer().unwrap(), 1);
+    Python::with_gil(|py| set_answer(py, 2)).unwrap();
+    assert_eq!(answer().unwrap(), 2);
+}
+
+#[test]
+fn invalidate_cached_bindings_resolves_again() {
+    assert_eq!(hypot(3.0, 4.0).unwrap(), 5.0);
+
+    // The cached callable is still used after it is replaced in its module
+    Python::with_gil(|py| {
+        py.run(
+            "import math; math.original_hypot = math.hypot; math.hypot = lambda x, y: -1.0",
+            None,
+            None,
+        )
+        .unwrap();
+    });
+    assert_eq!(hypot(3.0, 4.0).unwrap(), 5.0);
+
+    // The replacement is resolved after the cache is invalidated
+    invalidate_cached_bindings();
+    assert_eq!(hypot(3.0, 4.0).unwrap(), -1.0);
+
+    Python::with_gil(|py| {
+        py.run("import math; math.hypot = math.original_hypot", None, None)
+            .unwrap();
+    });
+    invalidate_cached_bindings();
+    assert_eq!(hypot(3.0, 4.0).unwrap(), 5.0);
+}